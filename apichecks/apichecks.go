@@ -0,0 +1,83 @@
+package apichecks
+
+import (
+	"context"
+	"errors"
+	"io/ioutil"
+	"net/http"
+)
+
+// Send makes a request through the proxies of the scan.
+type Send func(ctx context.Context, method, target, body string, headers http.Header) (*http.Response, error)
+
+// Record logs a request that can't go through Send, for the audit log.
+type Record func(method, target string, status int, err error)
+
+// Account is what the api of a site tells about a username.
+type Account struct {
+	Exists bool
+	// Profile url of the account when it is not the url of the site, such as the one of its id.
+	Link   string
+	Name   string
+	Avatar string
+}
+
+// Checker looks up a username with the api of a site. An error means the api can't tell, and
+// the page of the site is checked as usual.
+type Checker func(ctx context.Context, username string) (Account, error)
+
+// ErrInconclusive is returned when the api does not know the username but the account may still
+// exist, for instance a kind of account the api does not resolve.
+var ErrInconclusive = errors.New("the api can't tell whether the account exists")
+
+// Config holds the credentials of the apis. The checkers of apis that need credentials are only
+// used when they are set.
+type Config struct {
+	Discord *DiscordConfig `json:"discord"`
+	Xbox    *XboxConfig    `json:"xbox"`
+	PSN     *PSNConfig     `json:"psn"`
+}
+
+// New returns the checkers of the configured apis by the name of their site in the database.
+func New(config Config, send Send, record Record) map[string]Checker {
+	checkers := map[string]Checker{}
+	var discordConfig DiscordConfig
+	if config.Discord != nil {
+		discordConfig = *config.Discord
+	}
+	checkers["Discord"] = discord(discordConfig, send)
+	checkers["NameMC (Minecraft.net skins)"] = minecraft(send)
+	if config.Xbox != nil && config.Xbox.OpenXBLKey != "" {
+		checkers["Xbox Gamertag"] = xbox(*config.Xbox, send)
+	}
+	if config.PSN != nil && config.PSN.NPSSO != "" {
+		checkers["PlayStation Network"] = psn(*config.PSN, send, record)
+	}
+	return checkers
+}
+
+// Requests are the requests the checkers make, by the name of their site, for --dry-run. {} is
+// the username.
+var Requests = map[string][]string{
+	"Discord": {
+		"GET https://discord.com/api/v10/users/{} (user ids, with a bot_token)",
+		"POST https://discord.com/api/v9/unique-username/username-attempt-unauthed",
+	},
+	"NameMC (Minecraft.net skins)": {"GET https://api.mojang.com/users/profiles/minecraft/{}"},
+	"Xbox Gamertag":                {"GET https://xbl.io/api/v2/search/{}"},
+	"PlayStation Network": {
+		"GET " + psnAuthURL + "authorize (once, not proxied)",
+		"POST " + psnAuthURL + "token (once)",
+		"GET https://us-prof.np.community.playstation.net/userProfile/v1/users/{}/profile2",
+	},
+}
+
+func read(ctx context.Context, send Send, method, target, body string, headers http.Header) (int, []byte, error) {
+	r, err := send(ctx, method, target, body, headers)
+	if err != nil {
+		return 0, nil, err
+	}
+	defer r.Body.Close()
+	data, err := ioutil.ReadAll(r.Body)
+	return r.StatusCode, data, err
+}
@@ -0,0 +1,77 @@
+package apichecks
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"regexp"
+	"strings"
+
+	"github.com/tidwall/gjson"
+)
+
+// DiscordConfig is the optional bot whose token looks up the accounts of user ids.
+type DiscordConfig struct {
+	BotToken string `json:"bot_token"`
+}
+
+const discordAPI = "https://discord.com/api/v10/"
+
+var snowflakePattern = regexp.MustCompile(`^[0-9]{17,20}$`)
+
+// discord tells whether a username is taken with the endpoint of the registration form, which
+// needs no account. With a bot token, user ids are looked up too, which also gives the name and
+// avatar of the account.
+func discord(config DiscordConfig, send Send) Checker {
+	return func(ctx context.Context, username string) (Account, error) {
+		if config.BotToken != "" && snowflakePattern.MatchString(username) {
+			account, err := discordUser(ctx, config.BotToken, username, send)
+			if err != ErrInconclusive {
+				return account, err
+			}
+			// Not a user id, an all digits username is possible too.
+		}
+
+		payload, _ := json.Marshal(map[string]string{"username": strings.ToLower(username)})
+		headers := http.Header{"Content-Type": {"application/json"}}
+		status, body, err := read(ctx, send, http.MethodPost, "https://discord.com/api/v9/unique-username/username-attempt-unauthed", string(payload), headers)
+		if err != nil {
+			return Account{}, err
+		}
+		taken := gjson.GetBytes(body, "taken")
+		if status != http.StatusOK || !taken.Exists() {
+			return Account{}, fmt.Errorf("discord answered %d", status)
+		}
+		return Account{Exists: taken.Bool()}, nil
+	}
+}
+
+func discordUser(ctx context.Context, token, id string, send Send) (Account, error) {
+	headers := http.Header{"Authorization": {"Bot " + token}}
+	status, body, err := read(ctx, send, http.MethodGet, discordAPI+"users/"+id, "", headers)
+	if err != nil {
+		return Account{}, err
+	}
+	switch status {
+	case http.StatusOK:
+	case http.StatusNotFound:
+		return Account{}, ErrInconclusive
+	default:
+		return Account{}, fmt.Errorf("discord answered %d: %s", status, gjson.GetBytes(body, "message").String())
+	}
+
+	user := gjson.ParseBytes(body)
+	account := Account{
+		Exists: true,
+		Link:   "https://discord.com/users/" + id,
+		Name:   user.Get("global_name").String(),
+	}
+	if account.Name == "" {
+		account.Name = user.Get("username").String()
+	}
+	if avatar := user.Get("avatar").String(); avatar != "" {
+		account.Avatar = "https://cdn.discordapp.com/avatars/" + id + "/" + avatar + ".png"
+	}
+	return account, nil
+}
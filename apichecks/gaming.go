@@ -0,0 +1,68 @@
+package apichecks
+
+import (
+	"context"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+
+	"github.com/tidwall/gjson"
+)
+
+// minecraft looks up the uuid of a Minecraft name with the Mojang api, which needs no account.
+// The profile url is the one of NameMC, whose page is often behind a Cloudflare challenge.
+func minecraft(send Send) Checker {
+	return func(ctx context.Context, username string) (Account, error) {
+		status, body, err := read(ctx, send, http.MethodGet, "https://api.mojang.com/users/profiles/minecraft/"+url.PathEscape(username), "", nil)
+		if err != nil {
+			return Account{}, err
+		}
+		switch status {
+		case http.StatusOK:
+		case http.StatusNoContent, http.StatusNotFound:
+			return Account{}, nil
+		default:
+			return Account{}, fmt.Errorf("mojang answered %d", status)
+		}
+		uuid := gjson.GetBytes(body, "id").String()
+		name := gjson.GetBytes(body, "name").String()
+		return Account{
+			Exists: true,
+			Link:   "https://namemc.com/profile/" + name,
+			Name:   name,
+			Avatar: "https://mc-heads.net/avatar/" + uuid,
+		}, nil
+	}
+}
+
+// XboxConfig is the OpenXBL account searching gamertags, Xbox Live itself needs a Microsoft
+// account signed in through OAuth.
+type XboxConfig struct {
+	OpenXBLKey string `json:"openxbl_key"`
+}
+
+func xbox(config XboxConfig, send Send) Checker {
+	return func(ctx context.Context, username string) (Account, error) {
+		headers := http.Header{"X-Authorization": {config.OpenXBLKey}, "Accept": {"application/json"}}
+		status, body, err := read(ctx, send, http.MethodGet, "https://xbl.io/api/v2/search/"+url.PathEscape(username), "", headers)
+		if err != nil {
+			return Account{}, err
+		}
+		if status != http.StatusOK {
+			return Account{}, fmt.Errorf("openxbl answered %d", status)
+		}
+		// The search also finds similar gamertags.
+		for _, person := range gjson.GetBytes(body, "people").Array() {
+			if !strings.EqualFold(person.Get("gamertag").String(), username) {
+				continue
+			}
+			return Account{
+				Exists: true,
+				Name:   person.Get("gamertag").String(),
+				Avatar: person.Get("displayPicRaw").String(),
+			}, nil
+		}
+		return Account{}, nil
+	}
+}
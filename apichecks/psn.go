@@ -0,0 +1,140 @@
+package apichecks
+
+import (
+	"context"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"sync"
+	"time"
+
+	"github.com/tidwall/gjson"
+)
+
+// PSNConfig is the npsso cookie of a signed in PlayStation account, read from
+// https://ca.account.sony.com/api/v1/ssocookie. It lasts about two months.
+type PSNConfig struct {
+	NPSSO string `json:"npsso"`
+}
+
+// The client of the PlayStation app, which the profile api accepts the tokens of.
+const (
+	psnAuthURL     = "https://ca.account.sony.com/api/authz/v3/oauth/"
+	psnClientID    = "09515159-7237-4370-9b40-3806e67c0891"
+	psnClientAuth  = "MDk1MTUxNTktNzIzNy00MzcwLTliNDAtMzgwNmU2N2MwODkxOnVjUGprYTV0bnRCMktxc1A="
+	psnRedirectURI = "com.scee.psxandroid.scecompcall://redirect"
+)
+
+// psn looks up online ids with the profile api of the PlayStation app. The npsso is exchanged for
+// an access token, which is renewed when it expires.
+func psn(config PSNConfig, send Send, record Record) Checker {
+	var (
+		mutex   sync.Mutex
+		token   string
+		expires time.Time
+	)
+	accessToken := func(ctx context.Context) (string, error) {
+		mutex.Lock()
+		defer mutex.Unlock()
+		if token != "" && time.Now().Before(expires) {
+			return token, nil
+		}
+		var err error
+		var lifetime time.Duration
+		token, lifetime, err = psnToken(ctx, config.NPSSO, send, record)
+		if err != nil {
+			return "", err
+		}
+		expires = time.Now().Add(lifetime - time.Minute)
+		return token, nil
+	}
+
+	return func(ctx context.Context, username string) (Account, error) {
+		bearer, err := accessToken(ctx)
+		if err != nil {
+			return Account{}, err
+		}
+		headers := http.Header{"Authorization": {"Bearer " + bearer}}
+		target := "https://us-prof.np.community.playstation.net/userProfile/v1/users/" + url.PathEscape(username) +
+			"/profile2?fields=onlineId,accountId,avatarUrls"
+		status, body, err := read(ctx, send, http.MethodGet, target, "", headers)
+		if err != nil {
+			return Account{}, err
+		}
+		switch status {
+		case http.StatusOK:
+		case http.StatusNotFound:
+			return Account{}, nil
+		default:
+			return Account{}, fmt.Errorf("playstation answered %d", status)
+		}
+		profile := gjson.GetBytes(body, "profile")
+		account := Account{Exists: true, Name: profile.Get("onlineId").String()}
+		// The largest avatar comes last.
+		if avatars := profile.Get("avatarUrls").Array(); len(avatars) > 0 {
+			account.Avatar = avatars[len(avatars)-1].Get("avatarUrl").String()
+		}
+		return account, nil
+	}
+}
+
+// psnToken signs in with the npsso: the authorization endpoint redirects to the app with a code,
+// which is exchanged for an access token. The app's redirect can't be followed, so the request of
+// the authorization doesn't go through the proxies of the scan and is only recorded.
+func psnToken(ctx context.Context, npsso string, send Send, record Record) (string, time.Duration, error) {
+	client := &http.Client{
+		Timeout: 30 * time.Second,
+		CheckRedirect: func(*http.Request, []*http.Request) error {
+			return http.ErrUseLastResponse
+		},
+	}
+
+	authorize, err := http.NewRequestWithContext(ctx, http.MethodGet, psnAuthURL+"authorize?"+url.Values{
+		"access_type":   {"offline"},
+		"client_id":     {psnClientID},
+		"redirect_uri":  {psnRedirectURI},
+		"response_type": {"code"},
+		"scope":         {"psn:mobile.v2.core psn:clientapp"},
+	}.Encode(), nil)
+	if err != nil {
+		return "", 0, err
+	}
+	authorize.Header.Set("Cookie", "npsso="+npsso)
+	r, err := client.Do(authorize)
+	if err != nil {
+		record(authorize.Method, psnAuthURL+"authorize", 0, err)
+		return "", 0, err
+	}
+	record(authorize.Method, psnAuthURL+"authorize", r.StatusCode, nil)
+	r.Body.Close()
+	location, err := url.Parse(r.Header.Get("Location"))
+	if err != nil {
+		return "", 0, err
+	}
+	code := location.Query().Get("code")
+	if code == "" {
+		return "", 0, errors.New("playstation did not accept the npsso, it may have expired")
+	}
+
+	form := url.Values{
+		"code":         {code},
+		"redirect_uri": {psnRedirectURI},
+		"grant_type":   {"authorization_code"},
+		"token_format": {"jwt"},
+	}
+	headers := http.Header{
+		"Content-Type":  {"application/x-www-form-urlencoded"},
+		"Authorization": {"Basic " + psnClientAuth},
+	}
+	status, body, err := read(ctx, send, http.MethodPost, psnAuthURL+"token", form.Encode(), headers)
+	if err != nil {
+		return "", 0, err
+	}
+	if status != http.StatusOK {
+		return "", 0, fmt.Errorf("playstation answered %d to the token exchange", status)
+	}
+	token := gjson.GetBytes(body, "access_token").String()
+	lifetime := time.Duration(gjson.GetBytes(body, "expires_in").Int()) * time.Second
+	return token, lifetime, nil
+}
@@ -0,0 +1,108 @@
+package archive
+
+import (
+	"archive/zip"
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"io"
+	"io/ioutil"
+	"os"
+	"path/filepath"
+	"time"
+)
+
+// Entry is a file of the archive as listed in its manifest.
+type Entry struct {
+	Name   string `json:"name"`
+	Size   int    `json:"size"`
+	SHA256 string `json:"sha256"`
+}
+
+// Archive is a zip of the artifacts of a scan, with a manifest.json of their hashes.
+type Archive struct {
+	out      io.WriteCloser
+	zip      *zip.Writer
+	manifest []Entry
+}
+
+// Create starts a new archive at path.
+func Create(path string) (*Archive, error) {
+	file, err := os.Create(path)
+	if err != nil {
+		return nil, err
+	}
+	return New(file), nil
+}
+
+// New starts a new archive written to out, such as the input of an encryption tool.
+func New(out io.WriteCloser) *Archive {
+	return &Archive{out: out, zip: zip.NewWriter(out)}
+}
+
+// Add stores data under name.
+func (archive *Archive) Add(name string, data []byte) error {
+	writer, err := archive.zip.CreateHeader(&zip.FileHeader{
+		Name:     filepath.ToSlash(name),
+		Method:   zip.Deflate,
+		Modified: time.Now(),
+	})
+	if err != nil {
+		return err
+	}
+	if _, err := writer.Write(data); err != nil {
+		return err
+	}
+
+	sum := sha256.Sum256(data)
+	archive.manifest = append(archive.manifest, Entry{
+		Name:   filepath.ToSlash(name),
+		Size:   len(data),
+		SHA256: hex.EncodeToString(sum[:]),
+	})
+	return nil
+}
+
+// AddDir stores the files below dir under their path and returns the paths it stored, it does
+// nothing if dir doesn't exist.
+func (archive *Archive) AddDir(dir string) ([]string, error) {
+	if _, err := os.Stat(dir); os.IsNotExist(err) {
+		return nil, nil
+	}
+	var added []string
+	err := filepath.Walk(dir, func(path string, info os.FileInfo, err error) error {
+		if err != nil || info.IsDir() {
+			return err
+		}
+		data, err := ioutil.ReadFile(path)
+		if err != nil {
+			return err
+		}
+		if err := archive.Add(path, data); err != nil {
+			return err
+		}
+		added = append(added, path)
+		return nil
+	})
+	return added, err
+}
+
+// Close writes the manifest and finishes the zip.
+func (archive *Archive) Close() error {
+	manifest, err := json.MarshalIndent(archive.manifest, "", "  ")
+	if err != nil {
+		return err
+	}
+	writer, err := archive.zip.Create("manifest.json")
+	if err != nil {
+		return err
+	}
+	if _, err := writer.Write(manifest); err != nil {
+		return err
+	}
+	if err := archive.zip.Close(); err != nil {
+		archive.out.Close()
+		return err
+	}
+	return archive.out.Close()
+}
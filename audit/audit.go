@@ -0,0 +1,95 @@
+package audit
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"encoding/json"
+	"hash"
+	"io"
+	"os"
+	"sync"
+	"time"
+)
+
+// Entry is the record of a request: what was asked, the way it went out and a hash of the body
+// that was received, to tell later that evidence taken from the scan is what the site answered.
+type Entry struct {
+	Time   time.Time `json:"time"`
+	Method string    `json:"method"`
+	URL    string    `json:"url"`
+	// Url of the last response when the site redirected.
+	FinalURL string `json:"final_url,omitempty"`
+	// direct, tor, i2p or the url of the proxy without its credentials.
+	Exit       string `json:"exit"`
+	RemoteAddr string `json:"remote_addr,omitempty"`
+	Status     int    `json:"status,omitempty"`
+	Error      string `json:"error,omitempty"`
+	// SHA-256 of the body, as decoded by net/http. Only the BodyBytes that were
+	// read are hashed when the body was read in part, e.g. for sites with request_head_only.
+	BodySHA256 string `json:"body_sha256,omitempty"`
+	BodyBytes  int64  `json:"body_bytes"`
+}
+
+// Log appends entries to a JSON lines file. The file is only ever appended to, entries of earlier
+// scans are kept.
+type Log struct {
+	mutex   sync.Mutex
+	file    *os.File
+	encoder *json.Encoder
+}
+
+// Open opens the log at path, creating it if needed.
+func Open(path string) (*Log, error) {
+	file, err := os.OpenFile(path, os.O_APPEND|os.O_CREATE|os.O_WRONLY, 0600)
+	if err != nil {
+		return nil, err
+	}
+	return &Log{file: file, encoder: json.NewEncoder(file)}, nil
+}
+
+// Record appends an entry.
+func (log *Log) Record(entry Entry) error {
+	log.mutex.Lock()
+	defer log.mutex.Unlock()
+	return log.encoder.Encode(entry)
+}
+
+// Body records the entry once the body is closed, with the hash of what was read from it. The
+// responses whose body is never closed are not recorded.
+func (log *Log) Body(entry Entry, body io.ReadCloser) io.ReadCloser {
+	return &hashedBody{ReadCloser: body, log: log, entry: entry, hash: sha256.New()}
+}
+
+// Close closes the file of the log.
+func (log *Log) Close() error {
+	log.mutex.Lock()
+	defer log.mutex.Unlock()
+	return log.file.Close()
+}
+
+type hashedBody struct {
+	io.ReadCloser
+	log    *Log
+	entry  Entry
+	hash   hash.Hash
+	read   int64
+	closed bool
+}
+
+func (body *hashedBody) Read(p []byte) (int, error) {
+	n, err := body.ReadCloser.Read(p)
+	body.hash.Write(p[:n])
+	body.read += int64(n)
+	return n, err
+}
+
+func (body *hashedBody) Close() error {
+	err := body.ReadCloser.Close()
+	if !body.closed {
+		body.closed = true
+		body.entry.BodySHA256 = hex.EncodeToString(body.hash.Sum(nil))
+		body.entry.BodyBytes = body.read
+		body.log.Record(body.entry)
+	}
+	return err
+}
@@ -0,0 +1,90 @@
+package browsers
+
+import (
+	"net/http"
+	"sort"
+)
+
+// Profile is the set of headers a browser sends when navigating to a page, consistent with each
+// other so the user agent does not contradict the client hints or the Accept headers. They are
+// listed in the browser's order, although net/http sends them sorted by name.
+type Profile struct {
+	Name    string
+	Headers [][2]string
+}
+
+var profiles = map[string]Profile{
+	"chrome-windows": {
+		Name: "chrome-windows",
+		Headers: [][2]string{
+			{"sec-ch-ua", `"Chromium";v="118", "Google Chrome";v="118", "Not=A?Brand";v="99"`},
+			{"sec-ch-ua-mobile", "?0"},
+			{"sec-ch-ua-platform", `"Windows"`},
+			{"Upgrade-Insecure-Requests", "1"},
+			{"User-Agent", "Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/118.0.0.0 Safari/537.36"},
+			{"Accept", "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.7"},
+			{"Sec-Fetch-Site", "none"},
+			{"Sec-Fetch-Mode", "navigate"},
+			{"Sec-Fetch-User", "?1"},
+			{"Sec-Fetch-Dest", "document"},
+			{"Accept-Language", "en-US,en;q=0.9"},
+		},
+	},
+	"firefox-linux": {
+		Name: "firefox-linux",
+		Headers: [][2]string{
+			{"User-Agent", "Mozilla/5.0 (X11; Linux x86_64; rv:119.0) Gecko/20100101 Firefox/119.0"},
+			{"Accept", "text/html,application/xhtml+xml,application/xml;q=0.9,image/avif,image/webp,*/*;q=0.8"},
+			{"Accept-Language", "en-US,en;q=0.5"},
+			{"Upgrade-Insecure-Requests", "1"},
+			{"Sec-Fetch-Dest", "document"},
+			{"Sec-Fetch-Mode", "navigate"},
+			{"Sec-Fetch-Site", "none"},
+			{"Sec-Fetch-User", "?1"},
+		},
+	},
+	"safari-ios": {
+		Name: "safari-ios",
+		Headers: [][2]string{
+			{"Accept", "text/html,application/xhtml+xml,application/xml;q=0.9,*/*;q=0.8"},
+			{"Sec-Fetch-Site", "none"},
+			{"Sec-Fetch-Mode", "navigate"},
+			{"User-Agent", "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/17.0 Mobile/15E148 Safari/604.1"},
+			{"Accept-Language", "en-US,en;q=0.9"},
+			{"Sec-Fetch-Dest", "document"},
+		},
+	},
+}
+
+// Get returns the profile of the name.
+func Get(name string) (Profile, bool) {
+	profile, ok := profiles[name]
+	return profile, ok
+}
+
+// Names lists the profiles.
+func Names() []string {
+	var names []string
+	for name := range profiles {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// UserAgent returns the User-Agent of the profile.
+func (profile Profile) UserAgent() string {
+	for _, header := range profile.Headers {
+		if header[0] == "User-Agent" {
+			return header[1]
+		}
+	}
+	return ""
+}
+
+// Apply sets the headers of the profile on the request.
+func (profile Profile) Apply(header http.Header) {
+	for _, h := range profile.Headers {
+		header.Set(h[0], h[1])
+	}
+}
@@ -0,0 +1,193 @@
+package captcha
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"regexp"
+	"strings"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+// Config selects the solving service and the sites it may be used for. Solving costs money and
+// goes against the terms of most sites, so only the listed sites are ever solved.
+type Config struct {
+	// 2captcha or anti-captcha.
+	Service string   `json:"service"`
+	APIKey  string   `json:"api_key"`
+	Sites   []string `json:"sites"`
+}
+
+// Both services take the same createTask and getTaskResult calls.
+var endpoints = map[string]string{
+	"2captcha":     "https://api.2captcha.com",
+	"anti-captcha": "https://api.anti-captcha.com",
+}
+
+// Task types of the captcha providers maigret detects.
+var taskTypes = map[string]string{
+	"reCAPTCHA": "RecaptchaV2TaskProxyless",
+	"hCaptcha":  "HCaptchaTaskProxyless",
+	"Turnstile": "TurnstileTaskProxyless",
+}
+
+// Form fields the pages of the providers submit the token in.
+var responseFields = map[string]string{
+	"reCAPTCHA": "g-recaptcha-response",
+	"hCaptcha":  "h-captcha-response",
+	"Turnstile": "cf-turnstile-response",
+}
+
+var (
+	siteKeyPattern    = regexp.MustCompile(`(?i)data-sitekey=["']([^"']+)["']|sitekey["']?\s*:\s*["']([^"']+)["']`)
+	formActionPattern = regexp.MustCompile(`(?is)<form[^>]*\baction=["']([^"']*)["']`)
+)
+
+// Solver solves captchas through the service of the config.
+type Solver struct {
+	endpoint string
+	apiKey   string
+	sites    map[string]bool
+	client   *http.Client
+}
+
+// NewSolver checks the config and returns its solver.
+func NewSolver(config Config) (*Solver, error) {
+	endpoint, ok := endpoints[strings.ToLower(config.Service)]
+	if !ok {
+		return nil, fmt.Errorf("unknown captcha service \"%s\", use 2captcha or anti-captcha", config.Service)
+	}
+	if config.APIKey == "" {
+		return nil, errors.New("the captcha service needs an api_key")
+	}
+	solver := &Solver{
+		endpoint: endpoint,
+		apiKey:   config.APIKey,
+		sites:    map[string]bool{},
+		client:   &http.Client{Timeout: 30 * time.Second},
+	}
+	for _, site := range config.Sites {
+		solver.sites[strings.ToLower(site)] = true
+	}
+	return solver, nil
+}
+
+// Approved tells whether the user allowed solving the captchas of the site.
+func (solver *Solver) Approved(site string) bool {
+	return solver.sites[strings.ToLower(site)]
+}
+
+// Solve returns the token of the captcha of provider with siteKey on pageURL. It waits for a
+// worker of the service to solve it, which usually takes 10 to 60 seconds.
+func (solver *Solver) Solve(ctx context.Context, provider, siteKey, pageURL string) (string, error) {
+	taskType, ok := taskTypes[provider]
+	if !ok {
+		return "", fmt.Errorf("%s captchas are not supported", provider)
+	}
+
+	var created struct {
+		ErrorID          int    `json:"errorId"`
+		ErrorDescription string `json:"errorDescription"`
+		TaskID           int64  `json:"taskId"`
+	}
+	err := solver.call(ctx, "/createTask", map[string]interface{}{
+		"clientKey": solver.apiKey,
+		"task": map[string]string{
+			"type":       taskType,
+			"websiteURL": pageURL,
+			"websiteKey": siteKey,
+		},
+	}, &created)
+	if err != nil {
+		return "", err
+	}
+	if created.ErrorID != 0 {
+		return "", errors.New(created.ErrorDescription)
+	}
+
+	for {
+		select {
+		case <-ctx.Done():
+			return "", ctx.Err()
+		case <-time.After(5 * time.Second):
+		}
+
+		var result struct {
+			ErrorID          int    `json:"errorId"`
+			ErrorDescription string `json:"errorDescription"`
+			Status           string `json:"status"`
+			Solution         struct {
+				GRecaptchaResponse string `json:"gRecaptchaResponse"`
+				Token              string `json:"token"`
+			} `json:"solution"`
+		}
+		err := solver.call(ctx, "/getTaskResult", map[string]interface{}{
+			"clientKey": solver.apiKey,
+			"taskId":    created.TaskID,
+		}, &result)
+		if err != nil {
+			return "", err
+		}
+		if result.ErrorID != 0 {
+			return "", errors.New(result.ErrorDescription)
+		}
+		if result.Status == "ready" {
+			if result.Solution.Token != "" {
+				return result.Solution.Token, nil
+			}
+			return result.Solution.GRecaptchaResponse, nil
+		}
+	}
+}
+
+func (solver *Solver) call(ctx context.Context, path string, body interface{}, response interface{}) error {
+	payload, err := json.Marshal(body)
+	if err != nil {
+		return err
+	}
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, solver.endpoint+path, bytes.NewReader(payload))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	res, err := solver.client.Do(req)
+	if err != nil {
+		return err
+	}
+	defer res.Body.Close()
+	if res.StatusCode != http.StatusOK {
+		return fmt.Errorf("captcha service answered %s", res.Status)
+	}
+	return json.NewDecoder(res.Body).Decode(response)
+}
+
+// SiteKey returns the site key of the captcha widget of a challenge page.
+func SiteKey(page string) string {
+	match := siteKeyPattern.FindStringSubmatch(page)
+	if match == nil {
+		return ""
+	}
+	if match[1] != "" {
+		return match[1]
+	}
+	return match[2]
+}
+
+// Submission returns the url and the form body a challenge page would send the token with: the
+// action of its form, or the page itself.
+func Submission(page, pageURL, provider, token string) (string, string) {
+	target := pageURL
+	if match := formActionPattern.FindStringSubmatch(page); match != nil && match[1] != "" {
+		if base, err := url.Parse(pageURL); err == nil {
+			if action, err := base.Parse(match[1]); err == nil {
+				target = action.String()
+			}
+		}
+	}
+	return target, url.Values{responseFields[provider]: {token}}.Encode()
+}
@@ -210,3 +210,27 @@ func (chrome *Chrome) ScreenshotURL(targetURL *url.URL, destination string) {
 		"url": targetURL, "destination": destination, "duration": time.Since(startTime),
 	}).Info("Screenshot taken")
 }
+
+// DumpDOM returns the html of the page after its scripts ran.
+func (chrome *Chrome) DumpDOM(targetURL *url.URL) (string, error) {
+	chromeArguments := []string{
+		"--headless", "--disable-gpu", "--hide-scrollbars",
+		"--disable-crash-reporter", "--no-sandbox",
+		"--user-agent=" + chrome.UserAgent,
+		"--virtual-time-budget=" + strconv.Itoa(chrome.ChromeTimeBudget*1000),
+		"--dump-dom", targetURL.String(),
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), time.Duration(chrome.ChromeTimeout)*time.Second)
+	defer cancel()
+
+	log.WithFields(log.Fields{"url": targetURL}).Info("Loading page")
+	out, err := exec.CommandContext(ctx, chrome.Path, chromeArguments...).Output()
+	if err != nil {
+		if ctx.Err() == context.DeadlineExceeded {
+			return "", errors.New("Timeout reached while loading the page")
+		}
+		return "", errors.Wrap(err, "Loading the page failed")
+	}
+	return string(out), nil
+}
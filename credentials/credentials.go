@@ -0,0 +1,135 @@
+package credentials
+
+import (
+	"context"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"net/http"
+	"net/url"
+	"sync"
+	"time"
+)
+
+// Credential authenticates the api checks of the sites naming it, either with a fixed bearer
+// token, such as the one of an X app, or with OAuth client credentials exchanged for tokens, as
+// Reddit and Twitch apps do.
+type Credential struct {
+	Token string `json:"token"`
+	// Token endpoint of the client credentials grant.
+	TokenURL     string `json:"token_url"`
+	ClientID     string `json:"client_id"`
+	ClientSecret string `json:"client_secret"`
+	// How the client authenticates to the token endpoint: body, the default, sends the client id
+	// and secret as form fields, basic as the username and password of basic authentication.
+	ClientAuth string `json:"client_auth"`
+	// Headers the api also needs, such as the Client-Id of Twitch or the User-Agent of Reddit.
+	Headers map[string]string `json:"headers"`
+}
+
+type token struct {
+	value   string
+	expires time.Time
+}
+
+// Send makes a request through the proxies of the scan.
+type Send func(ctx context.Context, method, target, body string, headers http.Header) (*http.Response, error)
+
+// Store hands out the authorization headers of the configured credentials, and renews the tokens
+// of client credentials when they expire.
+type Store struct {
+	credentials map[string]Credential
+	send        Send
+	mutex       sync.Mutex
+	tokens      map[string]token
+}
+
+// NewStore returns the store of the credentials of the config, by their name, which requests
+// their tokens with send.
+func NewStore(credentials map[string]Credential, send Send) *Store {
+	return &Store{
+		credentials: credentials,
+		send:        send,
+		tokens:      map[string]token{},
+	}
+}
+
+// Has tells whether the credentials of the name are configured.
+func (store *Store) Has(name string) bool {
+	_, ok := store.credentials[name]
+	return ok
+}
+
+// Headers returns the headers of the requests made with the credentials of the name.
+func (store *Store) Headers(ctx context.Context, name string) (http.Header, error) {
+	credential, ok := store.credentials[name]
+	if !ok {
+		return nil, fmt.Errorf("no credentials named %s", name)
+	}
+	bearer := credential.Token
+	if bearer == "" {
+		var err error
+		if bearer, err = store.token(ctx, name, credential); err != nil {
+			return nil, err
+		}
+	}
+
+	headers := http.Header{}
+	for header, value := range credential.Headers {
+		headers.Set(header, value)
+	}
+	headers.Set("Authorization", "Bearer "+bearer)
+	return headers, nil
+}
+
+func (store *Store) token(ctx context.Context, name string, credential Credential) (string, error) {
+	store.mutex.Lock()
+	defer store.mutex.Unlock()
+	if cached, ok := store.tokens[name]; ok && time.Now().Before(cached.expires) {
+		return cached.value, nil
+	}
+	if credential.TokenURL == "" || credential.ClientID == "" {
+		return "", fmt.Errorf("the credentials %s need a token or a token_url and client_id", name)
+	}
+
+	form := url.Values{"grant_type": {"client_credentials"}}
+	if credential.ClientAuth != "basic" {
+		form.Set("client_id", credential.ClientID)
+		form.Set("client_secret", credential.ClientSecret)
+	}
+	headers := http.Header{"Content-Type": {"application/x-www-form-urlencoded"}}
+	if credential.ClientAuth == "basic" {
+		basic := base64.StdEncoding.EncodeToString([]byte(credential.ClientID + ":" + credential.ClientSecret))
+		headers.Set("Authorization", "Basic "+basic)
+	}
+	// Reddit wants the user agent of the app.
+	if userAgent, ok := credential.Headers["User-Agent"]; ok {
+		headers.Set("User-Agent", userAgent)
+	}
+	response, err := store.send(ctx, http.MethodPost, credential.TokenURL, form.Encode(), headers)
+	if err != nil {
+		return "", err
+	}
+	defer response.Body.Close()
+	if response.StatusCode != http.StatusOK {
+		return "", fmt.Errorf("the token endpoint of %s answered %s", name, response.Status)
+	}
+
+	var granted struct {
+		AccessToken string `json:"access_token"`
+		ExpiresIn   int    `json:"expires_in"`
+	}
+	if err := json.NewDecoder(response.Body).Decode(&granted); err != nil {
+		return "", err
+	}
+	if granted.AccessToken == "" {
+		return "", fmt.Errorf("the token endpoint of %s granted no token", name)
+	}
+	// Renewed a minute early, so a token does not expire during a scan's requests.
+	lifetime := time.Duration(granted.ExpiresIn)*time.Second - time.Minute
+	if granted.ExpiresIn == 0 {
+		lifetime = time.Hour
+	}
+	store.tokens[name] = token{value: granted.AccessToken, expires: time.Now().Add(lifetime)}
+	return granted.AccessToken, nil
+}
@@ -0,0 +1,91 @@
+package crtsh
+
+import (
+	"encoding/json"
+	"net/url"
+	"sort"
+	"strings"
+	"time"
+)
+
+// Fetch returns the body of a url, so the searches go through the same proxies as the checks.
+type Fetch func(target string) ([]byte, error)
+
+// Hostname is a name certificates were logged for in the certificate transparency logs.
+type Hostname struct {
+	Name         string    `json:"name"`
+	Certificates int       `json:"certificates"`
+	FirstSeen    time.Time `json:"first_seen"`
+	LastSeen     time.Time `json:"last_seen"`
+}
+
+type certificate struct {
+	NameValue string `json:"name_value"`
+	NotBefore string `json:"not_before"`
+}
+
+// Search returns the hostnames with the username as one of their labels, such as
+// username.github.io or blog.username.dev, that certificates were issued for. crt.sh matches
+// the beginning of names, so it is asked for names starting with the username and for its
+// subdomains.
+func Search(username string, fetch Fetch) ([]Hostname, error) {
+	username = strings.ToLower(username)
+	hostnames := map[string]*Hostname{}
+	var lastErr error
+	for _, pattern := range []string{username + ".%", "%." + username + ".%"} {
+		body, err := fetch("https://crt.sh/?" + url.Values{"q": {pattern}, "output": {"json"}}.Encode())
+		if err != nil {
+			lastErr = err
+			continue
+		}
+		var certificates []certificate
+		if err := json.Unmarshal(body, &certificates); err != nil {
+			lastErr = err
+			continue
+		}
+		for _, cert := range certificates {
+			issued, _ := time.Parse("2006-01-02T15:04:05", cert.NotBefore)
+			for _, name := range strings.Split(cert.NameValue, "\n") {
+				name = strings.TrimPrefix(strings.ToLower(strings.TrimSpace(name)), "*.")
+				if !hasLabel(name, username) {
+					continue
+				}
+				hostname, ok := hostnames[name]
+				if !ok {
+					hostname = &Hostname{Name: name, FirstSeen: issued, LastSeen: issued}
+					hostnames[name] = hostname
+				}
+				hostname.Certificates++
+				if issued.Before(hostname.FirstSeen) {
+					hostname.FirstSeen = issued
+				}
+				if issued.After(hostname.LastSeen) {
+					hostname.LastSeen = issued
+				}
+			}
+		}
+	}
+
+	var found []Hostname
+	for _, hostname := range hostnames {
+		found = append(found, *hostname)
+	}
+	sort.Slice(found, func(i, j int) bool {
+		return found[i].Name < found[j].Name
+	})
+	if len(found) > 0 {
+		return found, nil
+	}
+	return nil, lastErr
+}
+
+func hasLabel(name, label string) bool {
+	labels := strings.Split(name, ".")
+	// The last label is the top level domain.
+	for _, l := range labels[:len(labels)-1] {
+		if l == label {
+			return true
+		}
+	}
+	return false
+}
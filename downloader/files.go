@@ -0,0 +1,95 @@
+package downloader
+
+import (
+	"encoding/json"
+	"fmt"
+	"io"
+	"io/ioutil"
+	"net/http"
+	"os"
+	"path"
+	"strings"
+	"time"
+)
+
+// Get sends the requests of the downloaders. maigret replaces it to send them as it sends the
+// checks, through Tor or the proxies and with the timeout of the scan.
+var Get = func(url string, header http.Header) (*http.Response, error) {
+	req, err := http.NewRequest(http.MethodGet, url, nil)
+	if err != nil {
+		return nil, err
+	}
+	for name, values := range header {
+		req.Header[name] = values
+	}
+	client := &http.Client{Timeout: time.Minute}
+	return client.Do(req)
+}
+
+// lastSegment returns the username at the end of a profile url, with or without a trailing slash.
+func lastSegment(url string) string {
+	splitURL := strings.Split(strings.TrimRight(strings.Split(url, "?")[0], "/"), "/")
+	return splitURL[len(splitURL)-1]
+}
+
+// outputDir creates and returns the downloads directory of the username on the site.
+func outputDir(username, site string) string {
+	out := "./downloads/" + username + "/" + site + "/"
+	os.MkdirAll(out, os.ModePerm)
+	return out
+}
+
+func getPage(url string) (string, error) {
+	r, err := Get(url, nil)
+	if err != nil {
+		return "", err
+	}
+	defer r.Body.Close()
+	body, err := ioutil.ReadAll(r.Body)
+	return string(body), err
+}
+
+func getJSON(url string, header http.Header) ([]byte, error) {
+	header = header.Clone()
+	if header == nil {
+		header = http.Header{}
+	}
+	header.Set("Accept", "application/json")
+	r, err := Get(url, header)
+	if err != nil {
+		return nil, err
+	}
+	defer r.Body.Close()
+	if r.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("%s answered %s", r.Request.URL.Host, r.Status)
+	}
+	return ioutil.ReadAll(r.Body)
+}
+
+func saveJSON(fileName string, v interface{}) error {
+	data, err := json.MarshalIndent(v, "", "  ")
+	if err != nil {
+		return err
+	}
+	return ioutil.WriteFile(fileName, data, 0644)
+}
+
+// saveFile downloads url to fileName, with the extension of the url.
+func saveFile(fileName, url string) error {
+	r, err := Get(url, nil)
+	if err != nil {
+		return err
+	}
+	defer r.Body.Close()
+	if r.StatusCode != http.StatusOK {
+		return fmt.Errorf("%s answered %s", r.Request.URL.Host, r.Status)
+	}
+
+	file, err := os.Create(fileName + path.Ext(strings.Split(url, "?")[0]))
+	if err != nil {
+		return err
+	}
+	defer file.Close()
+	_, err = io.Copy(file, r.Body)
+	return err
+}
@@ -0,0 +1,162 @@
+package downloader
+
+import (
+	"errors"
+	"log"
+	"net/url"
+	"os"
+	"regexp"
+	"strconv"
+	"sync"
+
+	"github.com/tidwall/gjson"
+)
+
+// Most photos the metadata, EXIF and thumbnails are downloaded of, the most the API returns at once.
+const flickrMaxPhotos = 500
+
+var flickrSiteKeyPattern = regexp.MustCompile(`site_key\s*[:=]\s*"([0-9a-f]{32})"`)
+
+type flickrExif struct {
+	Tag   string `json:"tag"`
+	Label string `json:"label"`
+	Value string `json:"value"`
+}
+
+type flickrPhoto struct {
+	ID          string       `json:"id"`
+	Title       string       `json:"title"`
+	Description string       `json:"description"`
+	Taken       string       `json:"date_taken"`
+	Uploaded    string       `json:"date_uploaded"`
+	Tags        string       `json:"tags"`
+	Latitude    float64      `json:"latitude,omitempty"`
+	Longitude   float64      `json:"longitude,omitempty"`
+	Thumbnail   string       `json:"thumbnail"`
+	Camera      string       `json:"camera,omitempty"`
+	Exif        []flickrExif `json:"exif,omitempty"`
+}
+
+type flickrProfile struct {
+	ID          string        `json:"id"`
+	Username    string        `json:"username"`
+	RealName    string        `json:"real_name"`
+	Location    string        `json:"location"`
+	Description string        `json:"description"`
+	Photos      int64         `json:"photos"`
+	FirstTaken  string        `json:"first_taken"`
+	PhotoStream []flickrPhoto `json:"photostream"`
+}
+
+// flickrAPI calls a method of the Flickr REST API with the site key of the Flickr web app.
+func flickrAPI(key, method string, params url.Values) (gjson.Result, error) {
+	params.Set("method", method)
+	params.Set("api_key", key)
+	params.Set("format", "json")
+	params.Set("nojsoncallback", "1")
+	body, err := getJSON("https://api.flickr.com/services/rest/?"+params.Encode(), nil)
+	if err != nil {
+		return gjson.Result{}, err
+	}
+	result := gjson.ParseBytes(body)
+	if result.Get("stat").String() != "ok" {
+		return gjson.Result{}, errors.New(result.Get("message").String())
+	}
+	return result, nil
+}
+
+func downloadFlickr(profileURL string, logger *log.Logger) {
+	username := lastSegment(profileURL)
+	OUT := outputDir(username, "flickr")
+
+	page, err := getPage("https://www.flickr.com/")
+	if err != nil {
+		logger.Printf("[!] Flickr profile of %s: %s\n", username, err)
+		return
+	}
+	match := flickrSiteKeyPattern.FindStringSubmatch(page)
+	if match == nil {
+		logger.Printf("[!] Flickr profile of %s: no site key on flickr.com\n", username)
+		return
+	}
+	key := match[1]
+
+	lookup, err := flickrAPI(key, "flickr.urls.lookupUser", url.Values{"url": {profileURL}})
+	if err != nil {
+		logger.Printf("[!] Flickr profile of %s: %s\n", username, err)
+		return
+	}
+	userID := lookup.Get("user.id").String()
+
+	info, err := flickrAPI(key, "flickr.people.getInfo", url.Values{"user_id": {userID}})
+	if err != nil {
+		logger.Printf("[!] Flickr profile of %s: %s\n", username, err)
+		return
+	}
+	profile := flickrProfile{
+		ID:          userID,
+		Username:    info.Get("person.username._content").String(),
+		RealName:    info.Get("person.realname._content").String(),
+		Location:    info.Get("person.location._content").String(),
+		Description: info.Get("person.description._content").String(),
+		Photos:      info.Get("person.photos.count._content").Int(),
+		FirstTaken:  info.Get("person.photos.firstdatetaken._content").String(),
+	}
+
+	photos, err := flickrAPI(key, "flickr.people.getPublicPhotos", url.Values{
+		"user_id":  {userID},
+		"per_page": {strconv.Itoa(flickrMaxPhotos)},
+		"extras":   {"description,date_taken,date_upload,tags,geo,url_t"},
+	})
+	if err != nil {
+		logger.Printf("[!] Flickr photostream of %s: %s\n", username, err)
+	}
+	for _, photo := range photos.Get("photos.photo").Array() {
+		profile.PhotoStream = append(profile.PhotoStream, flickrPhoto{
+			ID:          photo.Get("id").String(),
+			Title:       photo.Get("title").String(),
+			Description: photo.Get("description._content").String(),
+			Taken:       photo.Get("datetaken").String(),
+			Uploaded:    photo.Get("dateupload").String(),
+			Tags:        photo.Get("tags").String(),
+			Latitude:    photo.Get("latitude").Float(),
+			Longitude:   photo.Get("longitude").Float(),
+			Thumbnail:   photo.Get("url_t").String(),
+		})
+	}
+
+	os.MkdirAll(OUT+"thumbnails/", os.ModePerm)
+	var wg sync.WaitGroup
+	workers := make(chan struct{}, 8)
+	for i := range profile.PhotoStream {
+		wg.Add(1)
+		workers <- struct{}{}
+		go func(photo *flickrPhoto) {
+			defer func() {
+				<-workers
+				wg.Done()
+			}()
+			// Owners can hide the EXIF of their photos, the call fails then.
+			if exif, err := flickrAPI(key, "flickr.photos.getExif", url.Values{"photo_id": {photo.ID}}); err == nil {
+				photo.Camera = exif.Get("photo.camera").String()
+				for _, tag := range exif.Get("photo.exif").Array() {
+					photo.Exif = append(photo.Exif, flickrExif{
+						Tag:   tag.Get("tag").String(),
+						Label: tag.Get("label").String(),
+						Value: tag.Get("raw._content").String(),
+					})
+				}
+			}
+			if photo.Thumbnail != "" {
+				if err := saveFile(OUT+"thumbnails/"+photo.ID, photo.Thumbnail); err != nil {
+					logger.Printf("[!] Flickr thumbnail %s of %s: %s\n", photo.ID, username, err)
+				}
+			}
+		}(&profile.PhotoStream[i])
+	}
+	wg.Wait()
+
+	if err := saveJSON(OUT+"profile.json", profile); err != nil {
+		logger.Printf("[!] Flickr profile of %s: %s\n", username, err)
+	}
+}
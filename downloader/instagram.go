@@ -4,7 +4,6 @@ import (
 	"io"
 	"io/ioutil"
 	"log"
-	"net/http"
 	"os"
 	"strconv"
 	"strings"
@@ -23,7 +22,7 @@ func downloadInstagram(url string, logger *log.Logger) {
 	var targetURIs []string
 	var wg sync.WaitGroup
 
-	r, err := http.Get(url + "?__a=1")
+	r, err := Get(url+"?__a=1", nil)
 	if err != nil {
 		log.Fatal(err)
 	}
@@ -64,7 +63,7 @@ func downloadInstagram(url string, logger *log.Logger) {
 				log.Fatal(err)
 			}
 
-			r, err := http.Get(uri)
+			r, err := Get(uri, nil)
 			if err != nil {
 				log.Fatal(err)
 			}
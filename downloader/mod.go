@@ -1,3 +1,9 @@
 package downloader
 
-var Impls = map[string]interface{}{"instagram": downloadInstagram}
+var Impls = map[string]interface{}{
+	"flickr":     downloadFlickr,
+	"instagram":  downloadInstagram,
+	"pinterest":  downloadPinterest,
+	"soundcloud": downloadSoundCloud,
+	"steam":      downloadSteam,
+}
@@ -0,0 +1,94 @@
+package downloader
+
+import (
+	"encoding/json"
+	"log"
+	"net/url"
+
+	"github.com/tidwall/gjson"
+)
+
+type pinterestBoard struct {
+	Name        string `json:"name"`
+	URL         string `json:"url"`
+	Description string `json:"description"`
+	Pins        int64  `json:"pins"`
+	Followers   int64  `json:"followers"`
+}
+
+type pinterestProfile struct {
+	Username  string           `json:"username"`
+	FullName  string           `json:"full_name"`
+	About     string           `json:"about"`
+	Website   string           `json:"website"`
+	Followers int64            `json:"followers"`
+	Following int64            `json:"following"`
+	Pins      int64            `json:"pins"`
+	Avatar    string           `json:"avatar"`
+	Boards    []pinterestBoard `json:"boards"`
+}
+
+// pinterestResource calls one of the resources the Pinterest web app loads its pages with.
+func pinterestResource(resource string, options map[string]interface{}) (gjson.Result, error) {
+	data, err := json.Marshal(map[string]interface{}{"options": options})
+	if err != nil {
+		return gjson.Result{}, err
+	}
+	body, err := getJSON("https://www.pinterest.com/resource/"+resource+"/get/?"+url.Values{"data": {string(data)}}.Encode(), nil)
+	if err != nil {
+		return gjson.Result{}, err
+	}
+	return gjson.GetBytes(body, "resource_response.data"), nil
+}
+
+func downloadPinterest(url string, logger *log.Logger) {
+	username := lastSegment(url)
+	OUT := outputDir(username, "pinterest")
+
+	user, err := pinterestResource("UserResource", map[string]interface{}{
+		"username":      username,
+		"field_set_key": "profile",
+	})
+	if err != nil {
+		logger.Printf("[!] Pinterest profile of %s: %s\n", username, err)
+		return
+	}
+	profile := pinterestProfile{
+		Username:  user.Get("username").String(),
+		FullName:  user.Get("full_name").String(),
+		About:     user.Get("about").String(),
+		Website:   user.Get("website_url").String(),
+		Followers: user.Get("follower_count").Int(),
+		Following: user.Get("following_count").Int(),
+		Pins:      user.Get("pin_count").Int(),
+		Avatar:    user.Get("image_xlarge_url").String(),
+	}
+
+	boards, err := pinterestResource("BoardsResource", map[string]interface{}{
+		"username":       username,
+		"page_size":      250,
+		"privacy_filter": "public",
+		"field_set_key":  "detailed",
+	})
+	if err != nil {
+		logger.Printf("[!] Pinterest boards of %s: %s\n", username, err)
+	}
+	for _, board := range boards.Array() {
+		profile.Boards = append(profile.Boards, pinterestBoard{
+			Name:        board.Get("name").String(),
+			URL:         "https://www.pinterest.com" + board.Get("url").String(),
+			Description: board.Get("description").String(),
+			Pins:        board.Get("pin_count").Int(),
+			Followers:   board.Get("follower_count").Int(),
+		})
+	}
+
+	if err := saveJSON(OUT+"profile.json", profile); err != nil {
+		logger.Printf("[!] Pinterest profile of %s: %s\n", username, err)
+	}
+	if profile.Avatar != "" {
+		if err := saveFile(OUT+"avatar", profile.Avatar); err != nil {
+			logger.Printf("[!] Pinterest avatar of %s: %s\n", username, err)
+		}
+	}
+}
@@ -0,0 +1,126 @@
+package downloader
+
+import (
+	"errors"
+	"log"
+	"net/url"
+	"regexp"
+	"strings"
+
+	"github.com/tidwall/gjson"
+)
+
+var (
+	soundcloudScriptPattern   = regexp.MustCompile(`<script[^>]+src="(https://a-v2\.sndcdn\.com/assets/[^"]+\.js)"`)
+	soundcloudClientIDPattern = regexp.MustCompile(`client_id\s*[:=]\s*"?([a-zA-Z0-9]{32})`)
+)
+
+type soundcloudTrack struct {
+	Title     string `json:"title"`
+	URL       string `json:"url"`
+	Created   string `json:"created_at"`
+	Duration  int64  `json:"duration_ms"`
+	Genre     string `json:"genre"`
+	Plays     int64  `json:"plays"`
+	Likes     int64  `json:"likes"`
+	Downloads int64  `json:"downloads"`
+}
+
+type soundcloudProfile struct {
+	ID          int64             `json:"id"`
+	Username    string            `json:"username"`
+	FullName    string            `json:"full_name"`
+	Description string            `json:"description"`
+	City        string            `json:"city"`
+	Country     string            `json:"country"`
+	Followers   int64             `json:"followers"`
+	Following   int64             `json:"following"`
+	Avatar      string            `json:"avatar"`
+	Tracks      []soundcloudTrack `json:"tracks"`
+}
+
+// soundcloudClientID finds the client_id the SoundCloud web app calls its API with in one of the
+// scripts of the homepage, so no API key of our own is needed.
+func soundcloudClientID() (string, error) {
+	page, err := getPage("https://soundcloud.com/")
+	if err != nil {
+		return "", err
+	}
+	scripts := soundcloudScriptPattern.FindAllStringSubmatch(page, -1)
+	// The client_id is in one of the last bundles.
+	for i := len(scripts) - 1; i >= 0; i-- {
+		script, err := getPage(scripts[i][1])
+		if err != nil {
+			continue
+		}
+		if match := soundcloudClientIDPattern.FindStringSubmatch(script); match != nil {
+			return match[1], nil
+		}
+	}
+	return "", errors.New("no client_id in the scripts of soundcloud.com")
+}
+
+func downloadSoundCloud(profileURL string, logger *log.Logger) {
+	username := lastSegment(profileURL)
+	OUT := outputDir(username, "soundcloud")
+
+	clientID, err := soundcloudClientID()
+	if err != nil {
+		logger.Printf("[!] SoundCloud profile of %s: %s\n", username, err)
+		return
+	}
+
+	body, err := getJSON("https://api-v2.soundcloud.com/resolve?"+url.Values{
+		"url":       {"https://soundcloud.com/" + username},
+		"client_id": {clientID},
+	}.Encode(), nil)
+	if err != nil {
+		logger.Printf("[!] SoundCloud profile of %s: %s\n", username, err)
+		return
+	}
+	user := gjson.ParseBytes(body)
+	profile := soundcloudProfile{
+		ID:          user.Get("id").Int(),
+		Username:    user.Get("permalink").String(),
+		FullName:    user.Get("full_name").String(),
+		Description: user.Get("description").String(),
+		City:        user.Get("city").String(),
+		Country:     user.Get("country_code").String(),
+		Followers:   user.Get("followers_count").Int(),
+		Following:   user.Get("followings_count").Int(),
+		// The API links the 100x100 version of the avatar.
+		Avatar:      strings.Replace(user.Get("avatar_url").String(), "-large.", "-t500x500.", 1),
+	}
+
+	next := "https://api-v2.soundcloud.com/users/" + user.Get("id").String() + "/tracks?limit=200"
+	for next != "" {
+		body, err := getJSON(next+"&client_id="+clientID, nil)
+		if err != nil {
+			logger.Printf("[!] SoundCloud tracks of %s: %s\n", username, err)
+			break
+		}
+		page := gjson.ParseBytes(body)
+		for _, track := range page.Get("collection").Array() {
+			profile.Tracks = append(profile.Tracks, soundcloudTrack{
+				Title:     track.Get("title").String(),
+				URL:       track.Get("permalink_url").String(),
+				Created:   track.Get("created_at").String(),
+				Duration:  track.Get("duration").Int(),
+				Genre:     track.Get("genre").String(),
+				Plays:     track.Get("playback_count").Int(),
+				Likes:     track.Get("likes_count").Int(),
+				Downloads: track.Get("download_count").Int(),
+			})
+		}
+		next = page.Get("next_href").String()
+	}
+
+	if err := saveJSON(OUT+"profile.json", profile); err != nil {
+		logger.Printf("[!] SoundCloud profile of %s: %s\n", username, err)
+	}
+	if profile.Avatar != "" {
+		if err := saveFile(OUT+"avatar", profile.Avatar); err != nil {
+			logger.Printf("[!] SoundCloud avatar of %s: %s\n", username, err)
+		}
+	}
+}
@@ -0,0 +1,87 @@
+package downloader
+
+import (
+	"encoding/json"
+	"encoding/xml"
+	"io/ioutil"
+	"log"
+	"strings"
+)
+
+type steamGroup struct {
+	ID   string `xml:"groupID64" json:"id"`
+	Name string `xml:"groupName" json:"name"`
+	URL  string `xml:"groupURL" json:"url"`
+}
+
+type steamGame struct {
+	AppID string `xml:"appID" json:"app_id"`
+	Name  string `xml:"name" json:"name"`
+	Hours string `xml:"hoursOnRecord" json:"hours,omitempty"`
+}
+
+type steamAlias struct {
+	Name    string `json:"newname"`
+	Changed string `json:"timechanged"`
+}
+
+type steamProfile struct {
+	SteamID64   string       `xml:"steamID64" json:"steam_id64"`
+	PersonaName string       `xml:"steamID" json:"persona_name"`
+	RealName    string       `xml:"realname" json:"real_name"`
+	Location    string       `xml:"location" json:"location"`
+	MemberSince string       `xml:"memberSince" json:"member_since"`
+	Summary     string       `xml:"summary" json:"summary"`
+	Privacy     string       `xml:"privacyState" json:"privacy"`
+	VACBanned   int          `xml:"vacBanned" json:"vac_banned"`
+	Avatar      string       `xml:"avatarFull" json:"avatar"`
+	Groups      []steamGroup `xml:"groups>group" json:"groups"`
+	Aliases     []steamAlias `xml:"-" json:"persona_history"`
+	Games       []steamGame  `xml:"-" json:"games"`
+}
+
+func getXML(url string, v interface{}) error {
+	r, err := Get(url, nil)
+	if err != nil {
+		return err
+	}
+	defer r.Body.Close()
+	body, err := ioutil.ReadAll(r.Body)
+	if err != nil {
+		return err
+	}
+	return xml.Unmarshal(body, v)
+}
+
+func downloadSteam(profileURL string, logger *log.Logger) {
+	username := lastSegment(profileURL)
+	OUT := outputDir(username, "steam")
+	base := strings.TrimRight(strings.Split(profileURL, "?")[0], "/")
+
+	var profile steamProfile
+	if err := getXML(base+"?xml=1", &profile); err != nil {
+		logger.Printf("[!] Steam profile of %s: %s\n", username, err)
+		return
+	}
+
+	// The names the profile had before, also listed for private profiles.
+	if body, err := getJSON(base+"/ajaxaliases/", nil); err == nil {
+		json.Unmarshal(body, &profile.Aliases)
+	}
+
+	var games struct {
+		Games []steamGame `xml:"games>game"`
+	}
+	if err := getXML(base+"/games?xml=1", &games); err == nil {
+		profile.Games = games.Games
+	}
+
+	if err := saveJSON(OUT+"profile.json", profile); err != nil {
+		logger.Printf("[!] Steam profile of %s: %s\n", username, err)
+	}
+	if profile.Avatar != "" {
+		if err := saveFile(OUT+"avatar", profile.Avatar); err != nil {
+			logger.Printf("[!] Steam avatar of %s: %s\n", username, err)
+		}
+	}
+}
@@ -0,0 +1,109 @@
+package emailfinder
+
+import (
+	"strings"
+	"unicode"
+)
+
+// Verdict of a verifier on whether a mailbox exists.
+type Verdict string
+
+const (
+	Valid   Verdict = "valid"
+	Invalid Verdict = "invalid"
+	// The server accepts any address of the domain, so it can't tell.
+	AcceptAll Verdict = "accept_all"
+	Unknown   Verdict = "unknown"
+)
+
+// Verifier checks which addresses of a domain have a mailbox.
+type Verifier interface {
+	Verify(emails []string) (map[string]Verdict, error)
+}
+
+// Patterns of corporate addresses, the most common first.
+var patterns = []string{
+	"{first}.{last}", "{first}", "{f}{last}", "{first}{last}", "{first}_{last}", "{f}.{last}",
+	"{first}{l}", "{first}-{last}", "{last}.{first}", "{last}{f}", "{last}", "{last}{first}",
+}
+
+// Permutations returns the likely addresses of a person at a company domain, from the first and
+// last words of the name.
+func Permutations(name, domain string) []string {
+	var words []string
+	for _, word := range strings.Fields(strings.ToLower(name)) {
+		if word = letters(word); word != "" {
+			words = append(words, word)
+		}
+	}
+	if len(words) == 0 {
+		return nil
+	}
+	first, last := words[0], words[len(words)-1]
+
+	var emails []string
+	seen := map[string]bool{}
+	for _, pattern := range patterns {
+		if len(words) == 1 && pattern != "{first}" {
+			continue
+		}
+		local := strings.NewReplacer(
+			"{first}", first,
+			"{last}", last,
+			"{f}", first[:1],
+			"{l}", last[:1],
+		).Replace(pattern)
+		email := local + "@" + strings.ToLower(domain)
+		if !seen[email] {
+			seen[email] = true
+			emails = append(emails, email)
+		}
+	}
+	return emails
+}
+
+// Accented letters as companies usually spell them in addresses.
+var unaccent = strings.NewReplacer(
+	"á", "a", "à", "a", "â", "a", "ä", "a", "ã", "a", "å", "a",
+	"é", "e", "è", "e", "ê", "e", "ë", "e",
+	"í", "i", "ì", "i", "î", "i", "ï", "i",
+	"ó", "o", "ò", "o", "ô", "o", "ö", "o", "õ", "o", "ø", "o",
+	"ú", "u", "ù", "u", "û", "u", "ü", "u",
+	"ç", "c", "ñ", "n", "ß", "ss",
+)
+
+// letters drops the characters of a word that can't be in an address, such as apostrophes.
+func letters(word string) string {
+	return strings.Map(func(r rune) rune {
+		if r < unicode.MaxASCII && (unicode.IsLetter(r) || unicode.IsDigit(r)) {
+			return r
+		}
+		return -1
+	}, unaccent.Replace(word))
+}
+
+// Find returns the addresses the verifier found a mailbox for, such as the permutations of a
+// name. When the domain accepts any address none of them can be told apart and AcceptAll is
+// returned.
+func Find(candidates []string, verifier Verifier) ([]string, Verdict, error) {
+	if len(candidates) == 0 {
+		return nil, Unknown, nil
+	}
+	verdicts, err := verifier.Verify(candidates)
+	if err != nil {
+		return nil, Unknown, err
+	}
+	var found []string
+	for _, email := range candidates {
+		switch verdicts[email] {
+		case Valid:
+			found = append(found, email)
+		case AcceptAll:
+			return nil, AcceptAll, nil
+		}
+	}
+	if len(found) == 0 {
+		return nil, Invalid, nil
+	}
+	return found, Valid, nil
+}
@@ -0,0 +1,79 @@
+package emailfinder
+
+import (
+	"errors"
+	"net/url"
+	"strings"
+
+	"github.com/tidwall/gjson"
+)
+
+// Fetch returns the body of a url, so the Hunter lookups go through the same proxies as the checks.
+type Fetch func(target string) ([]byte, error)
+
+// Hunter verifies addresses with the Hunter.io api, which also knows the address pattern of many
+// companies. Every verification uses a credit of the account.
+type Hunter struct {
+	Key   string
+	Fetch Fetch
+}
+
+const hunterAPI = "https://api.hunter.io/v2/"
+
+// Lookup returns the address Hunter found for the person, if any, which is worth verifying first.
+func (hunter Hunter) Lookup(name, domain string) (string, error) {
+	words := strings.Fields(name)
+	if len(words) < 2 {
+		return "", nil
+	}
+	body, err := hunter.Fetch(hunterAPI + "email-finder?" + url.Values{
+		"domain":     {domain},
+		"first_name": {words[0]},
+		"last_name":  {words[len(words)-1]},
+		"api_key":    {hunter.Key},
+	}.Encode())
+	if err != nil {
+		return "", hunter.redact(err)
+	}
+	return strings.ToLower(gjson.GetBytes(body, "data.email").String()), nil
+}
+
+// Verify asks Hunter about every address. Webmail and disposable addresses exist, an address
+// Hunter failed to verify, for instance because it is still being verified, is Unknown.
+func (hunter Hunter) Verify(emails []string) (map[string]Verdict, error) {
+	verdicts := map[string]Verdict{}
+	var answered bool
+	var lastErr error
+	for _, email := range emails {
+		verdicts[email] = Unknown
+		body, err := hunter.Fetch(hunterAPI + "email-verifier?" + url.Values{
+			"email":   {email},
+			"api_key": {hunter.Key},
+		}.Encode())
+		if err != nil {
+			lastErr = err
+			continue
+		}
+		answered = true
+		switch gjson.GetBytes(body, "data.status").String() {
+		case "valid", "webmail", "disposable":
+			verdicts[email] = Valid
+		case "invalid":
+			verdicts[email] = Invalid
+		case "accept_all":
+			verdicts[email] = AcceptAll
+		}
+	}
+	if !answered {
+		return nil, hunter.redact(lastErr)
+	}
+	return verdicts, nil
+}
+
+// redact drops the key from the urls in errors, which end up in the logs.
+func (hunter Hunter) redact(err error) error {
+	if err == nil || hunter.Key == "" {
+		return err
+	}
+	return errors.New(strings.ReplaceAll(err.Error(), url.QueryEscape(hunter.Key), "REDACTED"))
+}
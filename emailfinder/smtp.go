@@ -0,0 +1,112 @@
+package emailfinder
+
+import (
+	"errors"
+	"fmt"
+	"math/rand"
+	"net"
+	"net/smtp"
+	"net/textproto"
+	"strings"
+	"time"
+)
+
+// SMTP asks the mail server of the domain whether it would deliver to the addresses, without
+// sending anything. Many networks block outgoing connections to port 25, and some servers only
+// reject unknown recipients after the message, in which case every address is Unknown.
+type SMTP struct {
+	// Name the client introduces itself with, localhost by default.
+	HelloName string
+	Timeout   time.Duration
+	// Dialed is told of each connection attempt to a mail exchanger, when set.
+	Dialed func(address string, err error)
+}
+
+// Verify opens one session with the first reachable mail exchanger of the domain of the
+// addresses and checks every recipient in it. All the addresses must be of the same domain.
+func (verifier SMTP) Verify(emails []string) (map[string]Verdict, error) {
+	if len(emails) == 0 {
+		return nil, nil
+	}
+	domain := emails[0][strings.LastIndex(emails[0], "@")+1:]
+	client, err := verifier.dial(domain)
+	if err != nil {
+		return nil, err
+	}
+	defer client.Close()
+
+	if verifier.HelloName != "" {
+		if err := client.Hello(verifier.HelloName); err != nil {
+			return nil, err
+		}
+	}
+	// The null sender, as used by bounces, is accepted by every server.
+	if err := client.Mail(""); err != nil {
+		return nil, err
+	}
+
+	// A server that accepts a random address accepts them all.
+	random := fmt.Sprintf("maigret%d@%s", rand.New(rand.NewSource(time.Now().UnixNano())).Int63(), domain)
+	if rcpt(client, random) == Valid {
+		verdicts := map[string]Verdict{}
+		for _, email := range emails {
+			verdicts[email] = AcceptAll
+		}
+		return verdicts, nil
+	}
+
+	verdicts := map[string]Verdict{}
+	for _, email := range emails {
+		verdicts[email] = rcpt(client, email)
+	}
+	client.Quit()
+	return verdicts, nil
+}
+
+func (verifier SMTP) dial(domain string) (*smtp.Client, error) {
+	timeout := verifier.Timeout
+	if timeout == 0 {
+		timeout = 30 * time.Second
+	}
+	exchangers, err := net.LookupMX(domain)
+	if err != nil || len(exchangers) == 0 {
+		// Without MX records the domain itself receives the mail.
+		exchangers = []*net.MX{{Host: domain}}
+	}
+	var lastErr error
+	for _, mx := range exchangers {
+		host := strings.TrimSuffix(mx.Host, ".")
+		address := net.JoinHostPort(host, "25")
+		conn, err := net.DialTimeout("tcp", address, timeout)
+		if verifier.Dialed != nil {
+			verifier.Dialed(address, err)
+		}
+		if err != nil {
+			lastErr = err
+			continue
+		}
+		conn.SetDeadline(time.Now().Add(2 * timeout))
+		client, err := smtp.NewClient(conn, host)
+		if err != nil {
+			conn.Close()
+			lastErr = err
+			continue
+		}
+		return client, nil
+	}
+	return nil, lastErr
+}
+
+// rcpt reads the answer to the recipient: 250 and 251 accept it, 550 to 553 reject the mailbox,
+// anything else, such as greylisting, tells nothing.
+func rcpt(client *smtp.Client, email string) Verdict {
+	err := client.Rcpt(email)
+	if err == nil {
+		return Valid
+	}
+	var reply *textproto.Error
+	if errors.As(err, &reply) && reply.Code >= 550 && reply.Code <= 553 {
+		return Invalid
+	}
+	return Unknown
+}
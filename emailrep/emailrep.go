@@ -0,0 +1,102 @@
+package emailrep
+
+import (
+	"context"
+	"encoding/json"
+	"fmt"
+	"io/ioutil"
+	"net/http"
+	"net/url"
+	"strings"
+)
+
+// Reputation is what EmailRep knows about an email: how established and trustworthy it looks,
+// whether it leaked, and the platforms it has accounts on.
+type Reputation struct {
+	Email             string   `json:"email"`
+	Reputation        string   `json:"reputation"`
+	Suspicious        bool     `json:"suspicious"`
+	References        int      `json:"references"`
+	FirstSeen         string   `json:"first_seen,omitempty"`
+	LastSeen          string   `json:"last_seen,omitempty"`
+	DataBreach        bool     `json:"data_breach"`
+	CredentialsLeaked bool     `json:"credentials_leaked"`
+	Profiles          []string `json:"profiles,omitempty"`
+}
+
+// Names EmailRep gives platforms whose site in the database is named differently.
+var siteNames = map[string]string{
+	"aboutme": "About.me",
+	"lastfm":  "last.fm",
+}
+
+// Site returns the name of the site of a platform of the profiles as the database would spell
+// it, to be compared case-insensitively.
+func Site(platform string) string {
+	if name, ok := siteNames[strings.ToLower(platform)]; ok {
+		return name
+	}
+	return platform
+}
+
+// Send makes a request through the proxies of the scan.
+type Send func(ctx context.Context, method, target, body string, headers http.Header) (*http.Response, error)
+
+// Lookup asks EmailRep about the email. Without a key only a few lookups a day are allowed.
+func Lookup(ctx context.Context, email, key string, send Send) (Reputation, error) {
+	headers := http.Header{}
+	if key != "" {
+		headers.Set("Key", key)
+	}
+	res, err := send(ctx, http.MethodGet, "https://emailrep.io/"+url.PathEscape(email), "", headers)
+	if err != nil {
+		return Reputation{}, err
+	}
+	defer res.Body.Close()
+	body, err := ioutil.ReadAll(res.Body)
+	if err != nil {
+		return Reputation{}, err
+	}
+
+	var response struct {
+		Email      string `json:"email"`
+		Reputation string `json:"reputation"`
+		Suspicious bool   `json:"suspicious"`
+		References int    `json:"references"`
+		Reason     string `json:"reason"`
+		Details    struct {
+			FirstSeen         string   `json:"first_seen"`
+			LastSeen          string   `json:"last_seen"`
+			DataBreach        bool     `json:"data_breach"`
+			CredentialsLeaked bool     `json:"credentials_leaked"`
+			Profiles          []string `json:"profiles"`
+		} `json:"details"`
+	}
+	if err := json.Unmarshal(body, &response); err != nil {
+		return Reputation{}, err
+	}
+	if res.StatusCode != http.StatusOK {
+		if response.Reason != "" {
+			return Reputation{}, fmt.Errorf("emailrep: %s", response.Reason)
+		}
+		return Reputation{}, fmt.Errorf("emailrep answered %s", res.Status)
+	}
+
+	reputation := Reputation{
+		Email:             response.Email,
+		Reputation:        response.Reputation,
+		Suspicious:        response.Suspicious,
+		References:        response.References,
+		DataBreach:        response.Details.DataBreach,
+		CredentialsLeaked: response.Details.CredentialsLeaked,
+		Profiles:          response.Details.Profiles,
+	}
+	// Unknown dates are "never".
+	if response.Details.FirstSeen != "never" {
+		reputation.FirstSeen = response.Details.FirstSeen
+	}
+	if response.Details.LastSeen != "never" {
+		reputation.LastSeen = response.Details.LastSeen
+	}
+	return reputation, nil
+}
@@ -0,0 +1,80 @@
+package encrypt
+
+import (
+	"fmt"
+	"io"
+	"os"
+	"os/exec"
+	"strings"
+)
+
+// Tool returns the command line tool encrypting to a recipient and the extension of its files:
+// age for age and ssh public keys, gpg for the key ids, fingerprints and emails of PGP keys.
+func Tool(recipient string) (string, string) {
+	if strings.HasPrefix(recipient, "age1") || strings.HasPrefix(recipient, "ssh-") {
+		return "age", ".age"
+	}
+	return "gpg", ".gpg"
+}
+
+// Available tells whether the tool of the recipient is installed.
+func Available(recipient string) bool {
+	tool, _ := Tool(recipient)
+	_, err := exec.LookPath(tool)
+	return err == nil
+}
+
+// file is the input of the tool writing an encrypted file.
+type file struct {
+	io.WriteCloser
+	cmd *exec.Cmd
+}
+
+// Close ends the input and waits for the tool to write the file.
+func (f *file) Close() error {
+	if err := f.WriteCloser.Close(); err != nil {
+		return err
+	}
+	if err := f.cmd.Wait(); err != nil {
+		return fmt.Errorf("%s failed: %s", f.cmd.Path, err)
+	}
+	return nil
+}
+
+// Create returns a writer whose data is encrypted to the recipient into path with the extension
+// of the tool, and that path. The plain data is piped to the tool and never written to disk.
+func Create(path, recipient string) (io.WriteCloser, string, error) {
+	tool, extension := Tool(recipient)
+	path += extension
+	var cmd *exec.Cmd
+	if tool == "age" {
+		cmd = exec.Command("age", "--encrypt", "--recipient", recipient, "--output", path)
+	} else {
+		// The key is chosen explicitly, so it doesn't need to be certified in the keyring.
+		cmd = exec.Command("gpg", "--batch", "--yes", "--trust-model", "always",
+			"--encrypt", "--recipient", recipient, "--output", path)
+	}
+	cmd.Stderr = os.Stderr
+	stdin, err := cmd.StdinPipe()
+	if err != nil {
+		return nil, "", err
+	}
+	if err := cmd.Start(); err != nil {
+		return nil, "", err
+	}
+	return &file{WriteCloser: stdin, cmd: cmd}, path, nil
+}
+
+// WriteFile encrypts data to the recipient into path with the extension of the tool, and returns
+// that path.
+func WriteFile(path, recipient string, data []byte) (string, error) {
+	out, path, err := Create(path, recipient)
+	if err != nil {
+		return "", err
+	}
+	if _, err := out.Write(data); err != nil {
+		out.Close()
+		return "", err
+	}
+	return path, out.Close()
+}
@@ -0,0 +1,99 @@
+package engines
+
+import (
+	"sort"
+	"strings"
+)
+
+// Template holds what every site of an engine shares. Path is appended to the urlMain of the
+// site, with {} in place of the username.
+type Template struct {
+	Path         string
+	ErrorType    string
+	ErrorMsg     []string
+	PresenceStrs []string
+	// Markers of the pages the engine serves, in lower case, for Detect.
+	Markers []string
+}
+
+// Templates of the forum and CMS engines, by the name the database uses for them.
+var Templates = map[string]Template{
+	"XenForo": {
+		Path:      "/members/?username={}",
+		ErrorType: "message",
+		ErrorMsg:  []string{"The specified member cannot be found", "Указанный пользователь не найден"},
+		Markers:   []string{"xenforo", "xf-init"},
+	},
+	"Discourse": {
+		Path:      "/u/{}/summary",
+		ErrorType: "status_code",
+		Markers:   []string{`<meta name="generator" content="discourse`, "discourse-assets"},
+	},
+	"vBulletin": {
+		Path:      "/member.php?username={}",
+		ErrorType: "message",
+		ErrorMsg: []string{
+			"This user has not registered and therefore does not have a profile to view.",
+			"Пользователь не зарегистрирован и не имеет профиля для просмотра.",
+		},
+		Markers: []string{`<meta name="generator" content="vbulletin`, "vbulletin_"},
+	},
+	"phpBB": {
+		Path:      "/memberlist.php?mode=viewprofile&un={}",
+		ErrorType: "message",
+		ErrorMsg:  []string{"The requested user does not exist.", "Запрошенного пользователя не существует."},
+		Markers:   []string{"phpbb", "ucp.php?mode=login"},
+	},
+	"uCoz": {
+		Path:      "/index/8-0-{}",
+		ErrorType: "status_code",
+		Markers:   []string{"ucoz", "/.s/src/"},
+	},
+	"WordPress": {
+		Path:      "/author/{}/",
+		ErrorType: "status_code",
+		Markers:   []string{`<meta name="generator" content="wordpress`, "/wp-content/", "/wp-includes/"},
+	},
+}
+
+// Lookup returns the template of an engine, the name is case insensitive.
+func Lookup(name string) (string, Template, bool) {
+	for engine, template := range Templates {
+		if strings.EqualFold(engine, name) {
+			return engine, template, true
+		}
+	}
+	return "", Template{}, false
+}
+
+// Names lists the engines.
+func Names() []string {
+	var names []string
+	for name := range Templates {
+		names = append(names, name)
+	}
+	sort.Strings(names)
+	return names
+}
+
+// Detect returns the engine whose markers appear most often on the page, the homepage of a site.
+func Detect(page string) (string, bool) {
+	lower := strings.ToLower(page)
+	var best string
+	var bestCount int
+	for _, name := range Names() {
+		var count int
+		for _, marker := range Templates[name].Markers {
+			count += strings.Count(lower, marker)
+		}
+		if count > bestCount {
+			best, bestCount = name, count
+		}
+	}
+	return best, bestCount > 0
+}
+
+// URL returns the profile url template of a site of the engine.
+func (template Template) URL(urlMain string) string {
+	return strings.TrimRight(urlMain, "/") + template.Path
+}
@@ -0,0 +1,299 @@
+package exif
+
+import (
+	"bytes"
+	"encoding/binary"
+	"errors"
+	"regexp"
+	"strings"
+)
+
+// Metadata is what the EXIF and XMP of an image tell about where it was taken and with what. The
+// GPS position and the serial numbers tie photos of different accounts to the same person.
+type Metadata struct {
+	File             string            `json:"file"`
+	Make             string            `json:"make,omitempty"`
+	Model            string            `json:"model,omitempty"`
+	Software         string            `json:"software,omitempty"`
+	Artist           string            `json:"artist,omitempty"`
+	Copyright        string            `json:"copyright,omitempty"`
+	Taken            string            `json:"date_taken,omitempty"`
+	OwnerName        string            `json:"owner_name,omitempty"`
+	SerialNumber     string            `json:"serial_number,omitempty"`
+	LensSerialNumber string            `json:"lens_serial_number,omitempty"`
+	Latitude         *float64          `json:"latitude,omitempty"`
+	Longitude        *float64          `json:"longitude,omitempty"`
+	XMP              map[string]string `json:"xmp,omitempty"`
+}
+
+// Empty tells whether the image had no metadata.
+func (metadata Metadata) Empty() bool {
+	return metadata.Make == "" && metadata.Model == "" && metadata.Software == "" &&
+		metadata.Artist == "" && metadata.Copyright == "" && metadata.Taken == "" &&
+		metadata.OwnerName == "" && metadata.SerialNumber == "" && metadata.LensSerialNumber == "" &&
+		metadata.Latitude == nil && len(metadata.XMP) == 0
+}
+
+// Revealing tells whether the metadata has a GPS position or a serial number.
+func (metadata Metadata) Revealing() bool {
+	return metadata.Latitude != nil || metadata.SerialNumber != "" || metadata.LensSerialNumber != "" ||
+		metadata.XMP["aux:SerialNumber"] != ""
+}
+
+var errNoExif = errors.New("no EXIF")
+
+// Tags read from IFD0, the Exif IFD and the GPS IFD.
+const (
+	tagMake             = 0x010f
+	tagModel            = 0x0110
+	tagSoftware         = 0x0131
+	tagArtist           = 0x013b
+	tagCopyright        = 0x8298
+	tagExifIFD          = 0x8769
+	tagGPSIFD           = 0x8825
+	tagDateTimeOriginal = 0x9003
+	tagOwnerName        = 0xa430
+	tagSerialNumber     = 0xa431
+	tagLensSerialNumber = 0xa435
+	tagLatitudeRef      = 0x0001
+	tagLatitude         = 0x0002
+	tagLongitudeRef     = 0x0003
+	tagLongitude        = 0x0004
+)
+
+// XMP properties kept, the ones that locate or identify the photographer or the camera.
+var xmpProperties = []string{
+	"aux:SerialNumber", "aux:LensSerialNumber", "aux:OwnerName",
+	"exif:GPSLatitude", "exif:GPSLongitude",
+	"tiff:Make", "tiff:Model", "xmp:CreatorTool",
+	"photoshop:City", "photoshop:State", "photoshop:Country", "Iptc4xmpCore:Location",
+	"dc:creator", "dc:rights",
+}
+
+var (
+	xmpPattern = regexp.MustCompile(`(?s)<x:xmpmeta.*?</x:xmpmeta>`)
+	liPattern  = regexp.MustCompile(`(?s)<rdf:li[^>]*>(.*?)</rdf:li>`)
+)
+
+// The attribute or element of each XMP property.
+var xmpPropertyPatterns = map[string]*regexp.Regexp{}
+
+func init() {
+	for _, name := range xmpProperties {
+		quoted := regexp.QuoteMeta(name)
+		xmpPropertyPatterns[name] = regexp.MustCompile(`(?s)` + quoted + `="([^"]*)"|<` + quoted + `>(.*?)</` + quoted + `>`)
+	}
+}
+
+// Extract reads the EXIF of a JPEG, PNG, WebP or TIFF image and the XMP packet of any image.
+func Extract(data []byte) Metadata {
+	var metadata Metadata
+	if structure, err := findTIFF(data); err == nil {
+		readTIFF(structure, &metadata)
+	}
+	if packet := xmpPattern.Find(data); packet != nil {
+		metadata.XMP = readXMP(string(packet))
+	}
+	return metadata
+}
+
+// findTIFF returns the TIFF structure the EXIF of the image is stored in.
+func findTIFF(data []byte) ([]byte, error) {
+	switch {
+	case bytes.HasPrefix(data, []byte{0xff, 0xd8}):
+		// JPEG segments up to the image data, EXIF is in an APP1 segment.
+		for offset := 2; offset+4 <= len(data) && data[offset] == 0xff; {
+			marker := data[offset+1]
+			length := int(binary.BigEndian.Uint16(data[offset+2:]))
+			if marker == 0xda || length < 2 || offset+2+length > len(data) {
+				break
+			}
+			segment := data[offset+4 : offset+2+length]
+			if marker == 0xe1 && bytes.HasPrefix(segment, []byte("Exif\x00\x00")) {
+				return segment[6:], nil
+			}
+			offset += 2 + length
+		}
+	case bytes.HasPrefix(data, []byte("\x89PNG\r\n\x1a\n")):
+		for offset := 8; offset+8 <= len(data); {
+			length := int(binary.BigEndian.Uint32(data[offset:]))
+			if length < 0 || offset+12+length > len(data) {
+				break
+			}
+			if string(data[offset+4:offset+8]) == "eXIf" {
+				return data[offset+8 : offset+8+length], nil
+			}
+			offset += 12 + length
+		}
+	case len(data) >= 12 && string(data[:4]) == "RIFF" && string(data[8:12]) == "WEBP":
+		for offset := 12; offset+8 <= len(data); {
+			length := int(binary.LittleEndian.Uint32(data[offset+4:]))
+			if length < 0 || offset+8+length > len(data) {
+				break
+			}
+			if string(data[offset:offset+4]) == "EXIF" {
+				return bytes.TrimPrefix(data[offset+8:offset+8+length], []byte("Exif\x00\x00")), nil
+			}
+			// Chunks are padded to an even length.
+			offset += 8 + length + length%2
+		}
+	case bytes.HasPrefix(data, []byte("II*\x00")) || bytes.HasPrefix(data, []byte("MM\x00*")):
+		return data, nil
+	}
+	return nil, errNoExif
+}
+
+type entry struct {
+	kind  uint16
+	count uint32
+	value []byte
+}
+
+type tiff struct {
+	data  []byte
+	order binary.ByteOrder
+}
+
+// Sizes of the TIFF field types, 0 for the ones not read.
+var typeSizes = map[uint16]uint32{1: 1, 2: 1, 3: 2, 4: 4, 5: 8, 7: 1, 9: 4, 10: 8}
+
+func readTIFF(data []byte, metadata *Metadata) {
+	if len(data) < 8 {
+		return
+	}
+	t := tiff{data: data}
+	switch string(data[:2]) {
+	case "II":
+		t.order = binary.LittleEndian
+	case "MM":
+		t.order = binary.BigEndian
+	default:
+		return
+	}
+
+	ifd0 := t.ifd(t.order.Uint32(data[4:]))
+	metadata.Make = t.text(ifd0[tagMake])
+	metadata.Model = t.text(ifd0[tagModel])
+	metadata.Software = t.text(ifd0[tagSoftware])
+	metadata.Artist = t.text(ifd0[tagArtist])
+	metadata.Copyright = t.text(ifd0[tagCopyright])
+
+	if pointer, ok := t.long(ifd0[tagExifIFD]); ok {
+		exif := t.ifd(pointer)
+		metadata.Taken = t.text(exif[tagDateTimeOriginal])
+		metadata.OwnerName = t.text(exif[tagOwnerName])
+		metadata.SerialNumber = t.text(exif[tagSerialNumber])
+		metadata.LensSerialNumber = t.text(exif[tagLensSerialNumber])
+	}
+
+	if pointer, ok := t.long(ifd0[tagGPSIFD]); ok {
+		gps := t.ifd(pointer)
+		latitude, latOK := t.degrees(gps[tagLatitude], t.text(gps[tagLatitudeRef]) == "S")
+		longitude, lonOK := t.degrees(gps[tagLongitude], t.text(gps[tagLongitudeRef]) == "W")
+		// Cameras without a fix write zeros.
+		if latOK && lonOK && (latitude != 0 || longitude != 0) {
+			metadata.Latitude = &latitude
+			metadata.Longitude = &longitude
+		}
+	}
+}
+
+// ifd reads the entries of the image file directory at offset.
+func (t tiff) ifd(offset uint32) map[uint16]entry {
+	entries := map[uint16]entry{}
+	if uint64(offset)+2 > uint64(len(t.data)) {
+		return entries
+	}
+	n := int(t.order.Uint16(t.data[offset:]))
+	for i := 0; i < n; i++ {
+		start := int(offset) + 2 + i*12
+		if start+12 > len(t.data) {
+			break
+		}
+		raw := t.data[start : start+12]
+		e := entry{kind: t.order.Uint16(raw[2:]), count: t.order.Uint32(raw[4:])}
+		size := uint64(typeSizes[e.kind]) * uint64(e.count)
+		if size == 0 {
+			continue
+		}
+		if size <= 4 {
+			e.value = raw[8 : 8+size]
+		} else {
+			valueOffset := uint64(t.order.Uint32(raw[8:]))
+			if valueOffset+size > uint64(len(t.data)) {
+				continue
+			}
+			e.value = t.data[valueOffset : valueOffset+size]
+		}
+		entries[t.order.Uint16(raw)] = e
+	}
+	return entries
+}
+
+func (t tiff) text(e entry) string {
+	if e.kind != 2 && e.kind != 7 {
+		return ""
+	}
+	return strings.TrimSpace(strings.TrimRight(string(e.value), "\x00"))
+}
+
+func (t tiff) long(e entry) (uint32, bool) {
+	if e.kind != 4 || len(e.value) < 4 {
+		return 0, false
+	}
+	return t.order.Uint32(e.value), true
+}
+
+// degrees converts the degrees, minutes and seconds rationals of a GPS coordinate.
+func (t tiff) degrees(e entry, negative bool) (float64, bool) {
+	if e.kind != 5 || e.count != 3 {
+		return 0, false
+	}
+	var value float64
+	for i, unit := range []float64{1, 60, 3600} {
+		numerator := t.order.Uint32(e.value[i*8:])
+		denominator := t.order.Uint32(e.value[i*8+4:])
+		if denominator == 0 {
+			if numerator == 0 {
+				continue
+			}
+			return 0, false
+		}
+		value += float64(numerator) / float64(denominator) / unit
+	}
+	if negative {
+		value = -value
+	}
+	return value, true
+}
+
+// readXMP returns the kept properties of an XMP packet, written either as attributes or as
+// elements.
+func readXMP(packet string) map[string]string {
+	properties := map[string]string{}
+	for _, name := range xmpProperties {
+		match := xmpPropertyPatterns[name].FindStringSubmatch(packet)
+		if match == nil {
+			continue
+		}
+		value := match[1]
+		if value == "" {
+			// Lists such as dc:creator hold their values in rdf:li elements.
+			var items []string
+			for _, li := range liPattern.FindAllStringSubmatch(match[2], -1) {
+				items = append(items, strings.TrimSpace(li[1]))
+			}
+			value = strings.Join(items, ", ")
+			if value == "" {
+				value = strings.TrimSpace(match[2])
+			}
+		}
+		if value != "" {
+			properties[name] = value
+		}
+	}
+	if len(properties) == 0 {
+		return nil
+	}
+	return properties
+}
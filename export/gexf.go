@@ -0,0 +1,153 @@
+package export
+
+import (
+	"encoding/xml"
+	"fmt"
+	"time"
+)
+
+// Account is a found account as the exports see it.
+type Account struct {
+	Username   string
+	Site       string
+	URL        string
+	Confidence float64
+	Name       string
+	Avatar     string
+	Links      []string
+}
+
+type gexf struct {
+	XMLName xml.Name  `xml:"gexf"`
+	Xmlns   string    `xml:"xmlns,attr"`
+	Version string    `xml:"version,attr"`
+	Meta    gexfMeta  `xml:"meta"`
+	Graph   gexfGraph `xml:"graph"`
+}
+
+type gexfMeta struct {
+	LastModified string `xml:"lastmodifieddate,attr"`
+	Creator      string `xml:"creator"`
+}
+
+type gexfGraph struct {
+	DefaultEdgeType string         `xml:"defaultedgetype,attr"`
+	Attributes      gexfAttributes `xml:"attributes"`
+	Nodes           []gexfNode     `xml:"nodes>node"`
+	Edges           []gexfEdge     `xml:"edges>edge"`
+}
+
+type gexfAttributes struct {
+	Class      string          `xml:"class,attr"`
+	Attributes []gexfAttribute `xml:"attribute"`
+}
+
+type gexfAttribute struct {
+	ID    string `xml:"id,attr"`
+	Title string `xml:"title,attr"`
+	Type  string `xml:"type,attr"`
+}
+
+type gexfNode struct {
+	ID        string         `xml:"id,attr"`
+	Label     string         `xml:"label,attr"`
+	AttValues []gexfAttValue `xml:"attvalues>attvalue"`
+}
+
+type gexfAttValue struct {
+	For   string `xml:"for,attr"`
+	Value string `xml:"value,attr"`
+}
+
+type gexfEdge struct {
+	ID     string `xml:"id,attr"`
+	Source string `xml:"source,attr"`
+	Target string `xml:"target,attr"`
+	Label  string `xml:"label,attr,omitempty"`
+}
+
+// GEXF builds the identity graph of the accounts for Gephi. Usernames link to their accounts,
+// accounts link to the urls, display names and avatars of their profiles, so accounts sharing
+// one of them end up connected.
+func GEXF(accounts []Account) ([]byte, error) {
+	graph := gexfGraph{
+		DefaultEdgeType: "directed",
+		Attributes: gexfAttributes{
+			Class: "node",
+			Attributes: []gexfAttribute{
+				{ID: "type", Title: "type", Type: "string"},
+				{ID: "site", Title: "site", Type: "string"},
+				{ID: "url", Title: "url", Type: "string"},
+				{ID: "confidence", Title: "confidence", Type: "double"},
+			},
+		},
+	}
+
+	nodes := map[string]bool{}
+	addNode := func(id, label, kind string, values ...gexfAttValue) {
+		if nodes[id] {
+			return
+		}
+		nodes[id] = true
+		graph.Nodes = append(graph.Nodes, gexfNode{
+			ID:        id,
+			Label:     label,
+			AttValues: append([]gexfAttValue{{For: "type", Value: kind}}, values...),
+		})
+	}
+	addEdge := func(source, target, label string) {
+		graph.Edges = append(graph.Edges, gexfEdge{
+			ID:     fmt.Sprint(len(graph.Edges)),
+			Source: source,
+			Target: target,
+			Label:  label,
+		})
+	}
+
+	for _, account := range accounts {
+		usernameID := "username:" + account.Username
+		accountID := "account:" + account.URL
+		addNode(usernameID, account.Username, "username")
+		addNode(accountID, account.Site+": "+account.Username, "account",
+			gexfAttValue{For: "site", Value: account.Site},
+			gexfAttValue{For: "url", Value: account.URL},
+			gexfAttValue{For: "confidence", Value: fmt.Sprint(account.Confidence)},
+		)
+		addEdge(usernameID, accountID, "has account")
+	}
+
+	for _, account := range accounts {
+		accountID := "account:" + account.URL
+		if account.Name != "" {
+			addNode("name:"+account.Name, account.Name, "name")
+			addEdge(accountID, "name:"+account.Name, "display name")
+		}
+		if account.Avatar != "" {
+			addNode("avatar:"+account.Avatar, account.Avatar, "avatar", gexfAttValue{For: "url", Value: account.Avatar})
+			addEdge(accountID, "avatar:"+account.Avatar, "avatar")
+		}
+		for _, link := range account.Links {
+			// A link to another found account connects the two directly.
+			target := "account:" + link
+			if !nodes[target] {
+				target = "link:" + link
+				addNode(target, link, "link", gexfAttValue{For: "url", Value: link})
+			}
+			addEdge(accountID, target, "links to")
+		}
+	}
+
+	data, err := xml.MarshalIndent(gexf{
+		Xmlns:   "http://gexf.net/1.3",
+		Version: "1.3",
+		Meta: gexfMeta{
+			LastModified: time.Now().Format("2006-01-02"),
+			Creator:      "maigret",
+		},
+		Graph: graph,
+	}, "", "  ")
+	if err != nil {
+		return nil, err
+	}
+	return append([]byte(xml.Header), data...), nil
+}
@@ -0,0 +1,102 @@
+package export
+
+import (
+	"bytes"
+	"html/template"
+	"net/url"
+	"time"
+)
+
+// ReverseSearch is a link that looks up an image on a reverse image search engine.
+type ReverseSearch struct {
+	Engine string
+	URL    string
+}
+
+// ReverseImageSearches returns the Google Lens, Yandex and TinEye searches of an image url.
+func ReverseImageSearches(image string) []ReverseSearch {
+	escaped := url.QueryEscape(image)
+	return []ReverseSearch{
+		{Engine: "Google Lens", URL: "https://lens.google.com/uploadbyurl?url=" + escaped},
+		{Engine: "Yandex", URL: "https://yandex.com/images/search?rpt=imageview&url=" + escaped},
+		{Engine: "TinEye", URL: "https://tineye.com/search?url=" + escaped},
+	}
+}
+
+type htmlAccount struct {
+	Account
+	Searches []ReverseSearch
+}
+
+type htmlUsername struct {
+	Username string
+	Accounts []htmlAccount
+}
+
+var htmlTemplate = template.Must(template.New("report").Parse(`<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>maigret report</title>
+<style>
+body { font-family: sans-serif; margin: 2em; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 2em; }
+th, td { border-bottom: 1px solid #ddd; padding: 6px; text-align: left; vertical-align: middle; }
+img { width: 48px; height: 48px; object-fit: cover; border-radius: 4px; }
+.searches a { margin-right: 0.5em; }
+</style>
+</head>
+<body>
+<h1>maigret report</h1>
+<p>{{.Date}}</p>
+{{range .Usernames}}
+<h2>{{.Username}}</h2>
+{{if .Accounts}}
+<table>
+<tr><th>Avatar</th><th>Site</th><th>Account</th><th>Name</th><th>Confidence</th><th>Reverse image search</th></tr>
+{{range .Accounts}}
+<tr>
+<td>{{if .Avatar}}<img src="{{.Avatar}}" alt="" referrerpolicy="no-referrer">{{end}}</td>
+<td>{{.Site}}</td>
+<td><a href="{{.URL}}">{{.URL}}</a></td>
+<td>{{.Name}}</td>
+<td>{{printf "%.2f" .Confidence}}</td>
+<td class="searches">{{range .Searches}}<a href="{{.URL}}" target="_blank" rel="noopener noreferrer">{{.Engine}}</a>{{end}}</td>
+</tr>
+{{end}}
+</table>
+{{else}}
+<p>No accounts found.</p>
+{{end}}
+{{end}}
+</body>
+</html>
+`))
+
+// HTML renders the found accounts of every username as a page, in the order of the accounts, with
+// reverse image searches of their avatars to pivot on the profile pictures.
+func HTML(accounts []Account, usernames []string) ([]byte, error) {
+	byUsername := map[string][]htmlAccount{}
+	for _, account := range accounts {
+		entry := htmlAccount{Account: account}
+		if account.Avatar != "" {
+			entry.Searches = ReverseImageSearches(account.Avatar)
+		}
+		byUsername[account.Username] = append(byUsername[account.Username], entry)
+	}
+
+	var page struct {
+		Date      string
+		Usernames []htmlUsername
+	}
+	page.Date = time.Now().Format("2006-01-02 15:04:05")
+	for _, username := range usernames {
+		page.Usernames = append(page.Usernames, htmlUsername{Username: username, Accounts: byUsername[username]})
+	}
+
+	var buffer bytes.Buffer
+	if err := htmlTemplate.Execute(&buffer, page); err != nil {
+		return nil, err
+	}
+	return buffer.Bytes(), nil
+}
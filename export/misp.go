@@ -0,0 +1,86 @@
+package export
+
+import (
+	"encoding/json"
+	"fmt"
+	"sort"
+	"strings"
+	"time"
+)
+
+type mispEvent struct {
+	Event mispEventBody `json:"Event"`
+}
+
+type mispEventBody struct {
+	Info          string          `json:"info"`
+	Date          string          `json:"date"`
+	ThreatLevelID string          `json:"threat_level_id"`
+	Analysis      string          `json:"analysis"`
+	Distribution  string          `json:"distribution"`
+	Tag           []mispTag       `json:"Tag"`
+	Attribute     []mispAttribute `json:"Attribute"`
+}
+
+type mispAttribute struct {
+	Type     string    `json:"type"`
+	Category string    `json:"category"`
+	Value    string    `json:"value"`
+	Comment  string    `json:"comment,omitempty"`
+	ToIDS    bool      `json:"to_ids"`
+	Tag      []mispTag `json:"Tag,omitempty"`
+}
+
+type mispTag struct {
+	Name string `json:"name"`
+}
+
+// MISP builds a MISP event with a url attribute for every found account, tagged with its
+// confidence, and a text attribute for every username.
+func MISP(accounts []Account, usernames []string) ([]byte, error) {
+	event := mispEventBody{
+		Info:          "maigret scan of " + strings.Join(usernames, ", "),
+		Date:          time.Now().Format("2006-01-02"),
+		ThreatLevelID: "4",
+		Analysis:      "2",
+		Distribution:  "0",
+		Tag:           []mispTag{{Name: "tool:maigret"}},
+	}
+
+	for _, username := range usernames {
+		event.Attribute = append(event.Attribute, mispAttribute{
+			Type:     "text",
+			Category: "Social network",
+			Value:    username,
+			Comment:  "Investigated username",
+		})
+	}
+
+	sort.Slice(accounts, func(i, j int) bool {
+		return accounts[i].URL < accounts[j].URL
+	})
+	for _, account := range accounts {
+		event.Attribute = append(event.Attribute, mispAttribute{
+			Type:     "url",
+			Category: "Social network",
+			Value:    account.URL,
+			Comment:  fmt.Sprintf("%s account of %s", account.Site, account.Username),
+			Tag: []mispTag{
+				{Name: fmt.Sprintf("maigret:confidence=\"%.2f\"", account.Confidence)},
+				{Name: "estimative-language:confidence-in-analytic-judgment=\"" + confidenceLevel(account.Confidence) + "\""},
+			},
+		})
+	}
+
+	return json.MarshalIndent(mispEvent{Event: event}, "", "  ")
+}
+
+func confidenceLevel(confidence float64) string {
+	switch {
+	case confidence >= 0.8:
+		return "high"
+	case confidence >= 0.5:
+		return "moderate"
+	}
+	return "low"
+}
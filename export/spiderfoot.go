@@ -0,0 +1,51 @@
+package export
+
+import (
+	"encoding/json"
+	"fmt"
+	"sort"
+)
+
+// Target is an investigated username and the email it was taken from, if any.
+type Target struct {
+	Username string
+	Email    string
+}
+
+// SpiderFootEvent is an element in the form SpiderFoot modules produce.
+type SpiderFootEvent struct {
+	Type   string `json:"type"`
+	Data   string `json:"data"`
+	Source string `json:"source"`
+}
+
+// SpiderFoot lists the targets as USERNAME and EMAILADDR elements and the found accounts as
+// ACCOUNT_EXTERNAL_OWNED elements, with the data format SpiderFoot uses for them.
+func SpiderFoot(targets []Target, accounts []Account, categories map[string]string) ([]byte, error) {
+	var events []SpiderFootEvent
+	for _, target := range targets {
+		if target.Email != "" {
+			events = append(events, SpiderFootEvent{Type: "EMAILADDR", Data: target.Email, Source: "maigret"})
+			events = append(events, SpiderFootEvent{Type: "USERNAME", Data: target.Username, Source: target.Email})
+		} else {
+			events = append(events, SpiderFootEvent{Type: "USERNAME", Data: target.Username, Source: "maigret"})
+		}
+	}
+
+	sort.Slice(accounts, func(i, j int) bool {
+		return accounts[i].URL < accounts[j].URL
+	})
+	for _, account := range accounts {
+		category := categories[account.Site]
+		if category == "" {
+			category = "unknown"
+		}
+		events = append(events, SpiderFootEvent{
+			Type:   "ACCOUNT_EXTERNAL_OWNED",
+			Data:   fmt.Sprintf("%s (Category: %s)\n<SFURL>%s</SFURL>", account.Site, category, account.URL),
+			Source: account.Username,
+		})
+	}
+
+	return json.MarshalIndent(events, "", "  ")
+}
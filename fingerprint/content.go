@@ -0,0 +1,46 @@
+package fingerprint
+
+import (
+	"crypto/sha256"
+	"encoding/hex"
+	"strings"
+
+	"golang.org/x/net/html"
+)
+
+// ContentHash hashes the visible text of a page. Markup, scripts and numbers are left out, so
+// changing counters and tokens don't count as a change of the profile.
+func ContentHash(page string) string {
+	var words []string
+	var hidden int
+
+	tokenizer := html.NewTokenizer(strings.NewReader(page))
+	for {
+		switch tokenizer.Next() {
+		case html.ErrorToken:
+			sum := sha256.Sum256([]byte(strings.Join(words, " ")))
+			return hex.EncodeToString(sum[:16])
+		case html.StartTagToken:
+			if isHidden(tokenizer) {
+				hidden++
+			}
+		case html.EndTagToken:
+			if isHidden(tokenizer) && hidden > 0 {
+				hidden--
+			}
+		case html.TextToken:
+			if hidden == 0 {
+				words = append(words, tokenPattern.FindAllString(strings.ToLower(string(tokenizer.Text())), -1)...)
+			}
+		}
+	}
+}
+
+func isHidden(tokenizer *html.Tokenizer) bool {
+	name, _ := tokenizer.TagName()
+	switch string(name) {
+	case "script", "style", "noscript", "template":
+		return true
+	}
+	return false
+}
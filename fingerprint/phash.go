@@ -0,0 +1,80 @@
+package fingerprint
+
+import (
+	"image"
+	"math"
+	"sort"
+)
+
+// Size of the grayscale thumbnail the DCT is computed on, and of the low frequency block kept.
+const (
+	phashSize  = 32
+	phashBlock = 8
+)
+
+// PHash computes the 64 bit perceptual hash of an image: the signs of its lowest DCT frequencies
+// against their median. Resized, recompressed or slightly recolored copies of an image get hashes
+// a few bits apart, see Distance.
+func PHash(img image.Image) uint64 {
+	bounds := img.Bounds()
+	if bounds.Empty() {
+		return 0
+	}
+
+	// Box-filtered grayscale thumbnail.
+	var pixels [phashSize][phashSize]float64
+	for y := 0; y < phashSize; y++ {
+		y0 := bounds.Min.Y + y*bounds.Dy()/phashSize
+		y1 := bounds.Min.Y + (y+1)*bounds.Dy()/phashSize
+		if y1 == y0 {
+			y1 = y0 + 1
+		}
+		for x := 0; x < phashSize; x++ {
+			x0 := bounds.Min.X + x*bounds.Dx()/phashSize
+			x1 := bounds.Min.X + (x+1)*bounds.Dx()/phashSize
+			if x1 == x0 {
+				x1 = x0 + 1
+			}
+			var sum float64
+			for py := y0; py < y1; py++ {
+				for px := x0; px < x1; px++ {
+					r, g, b, _ := img.At(px, py).RGBA()
+					sum += 0.299*float64(r) + 0.587*float64(g) + 0.114*float64(b)
+				}
+			}
+			pixels[y][x] = sum / float64((y1-y0)*(x1-x0))
+		}
+	}
+
+	var cosines [phashBlock][phashSize]float64
+	for u := 0; u < phashBlock; u++ {
+		for x := 0; x < phashSize; x++ {
+			cosines[u][x] = math.Cos(float64(2*x+1) * float64(u) * math.Pi / (2 * phashSize))
+		}
+	}
+	var coefficients []float64
+	for v := 0; v < phashBlock; v++ {
+		for u := 0; u < phashBlock; u++ {
+			var sum float64
+			for y := 0; y < phashSize; y++ {
+				for x := 0; x < phashSize; x++ {
+					sum += pixels[y][x] * cosines[u][x] * cosines[v][y]
+				}
+			}
+			coefficients = append(coefficients, sum)
+		}
+	}
+
+	// The DC term is the mean brightness, it says nothing about the content.
+	sorted := append([]float64(nil), coefficients[1:]...)
+	sort.Float64s(sorted)
+	median := sorted[len(sorted)/2]
+
+	var hash uint64
+	for i, coefficient := range coefficients {
+		if i > 0 && coefficient > median {
+			hash |= 1 << uint(i)
+		}
+	}
+	return hash
+}
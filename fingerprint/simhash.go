@@ -0,0 +1,50 @@
+package fingerprint
+
+import (
+	"hash/fnv"
+	"math/bits"
+	"regexp"
+	"strings"
+)
+
+// Letters only, so numeric ids, timestamps and nonces don't change the fingerprint.
+var tokenPattern = regexp.MustCompile(`[a-z]+`)
+
+// Simhash computes a 64 bit similarity hash of a page from its word pairs. The exclude strings,
+// usually the username the page was requested for, are removed first.
+func Simhash(page string, exclude ...string) uint64 {
+	page = strings.ToLower(page)
+	for _, e := range exclude {
+		if e != "" {
+			page = strings.Replace(page, strings.ToLower(e), " ", -1)
+		}
+	}
+
+	var weights [64]int
+	tokens := tokenPattern.FindAllString(page, -1)
+	for i := 1; i < len(tokens); i++ {
+		h := fnv.New64a()
+		h.Write([]byte(tokens[i-1] + " " + tokens[i]))
+		sum := h.Sum64()
+		for bit := uint(0); bit < 64; bit++ {
+			if sum&(1<<bit) != 0 {
+				weights[bit]++
+			} else {
+				weights[bit]--
+			}
+		}
+	}
+
+	var hash uint64
+	for bit, weight := range weights {
+		if weight > 0 {
+			hash |= 1 << uint(bit)
+		}
+	}
+	return hash
+}
+
+// Distance is the number of differing bits of two hashes, near duplicate pages are within 3.
+func Distance(a, b uint64) int {
+	return bits.OnesCount64(a ^ b)
+}
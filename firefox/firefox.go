@@ -0,0 +1,220 @@
+package firefox
+
+import (
+	"bytes"
+	"context"
+	"encoding/base64"
+	"encoding/json"
+	"fmt"
+	"io/ioutil"
+	"net"
+	"net/http"
+	"os"
+	"os/exec"
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+// Firefox takes screenshots with a headless Firefox driven through geckodriver, for hosts
+// without Chrome.
+type Firefox struct {
+	Resolution string
+	Timeout    time.Duration
+	// Path of geckodriver, looked up in PATH when empty.
+	Path      string
+	UserAgent string
+}
+
+// Screenshot saves a png of targetURL to destination.
+func (firefox *Firefox) Screenshot(targetURL, destination string) error {
+	return firefox.visit(targetURL, func(driver *webDriver, sessionPath string) error {
+		var encoded string
+		if err := driver.call(http.MethodGet, sessionPath+"/screenshot", nil, &encoded); err != nil {
+			return errors.Wrap(err, "taking screenshot")
+		}
+		png, err := base64.StdEncoding.DecodeString(encoded)
+		if err != nil {
+			return err
+		}
+		return ioutil.WriteFile(destination, png, 0644)
+	})
+}
+
+// Source returns the html of targetURL after its scripts ran.
+func (firefox *Firefox) Source(targetURL string) (string, error) {
+	var source string
+	err := firefox.visit(targetURL, func(driver *webDriver, sessionPath string) error {
+		return driver.call(http.MethodGet, sessionPath+"/source", nil, &source)
+	})
+	return source, err
+}
+
+// visit starts geckodriver and a browser session, loads targetURL and hands the session to use.
+func (firefox *Firefox) visit(targetURL string, use func(driver *webDriver, sessionPath string) error) error {
+	path := firefox.Path
+	if path == "" {
+		found, err := exec.LookPath("geckodriver")
+		if err != nil {
+			return errors.New("geckodriver not found, install it or set its path in the config")
+		}
+		path = found
+	}
+
+	port, err := freePort()
+	if err != nil {
+		return err
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), firefox.Timeout)
+	defer cancel()
+
+	cmd := exec.CommandContext(ctx, path, "--port", strconv.Itoa(port))
+	if err := cmd.Start(); err != nil {
+		return errors.Wrap(err, "starting geckodriver")
+	}
+	defer func() {
+		cmd.Process.Kill()
+		cmd.Wait()
+	}()
+
+	driver := &webDriver{ctx: ctx, base: fmt.Sprintf("http://127.0.0.1:%d", port)}
+	if err := driver.waitReady(); err != nil {
+		return err
+	}
+
+	prefs := map[string]interface{}{}
+	if firefox.UserAgent != "" {
+		prefs["general.useragent.override"] = firefox.UserAgent
+	}
+	var session struct {
+		SessionID string `json:"sessionId"`
+	}
+	err = driver.call(http.MethodPost, "/session", map[string]interface{}{
+		"capabilities": map[string]interface{}{
+			"alwaysMatch": map[string]interface{}{
+				"acceptInsecureCerts": true,
+				"moz:firefoxOptions": map[string]interface{}{
+					"args":  []string{"-headless"},
+					"prefs": prefs,
+				},
+			},
+		},
+	}, &session)
+	if err != nil {
+		return errors.Wrap(err, "starting firefox")
+	}
+	sessionPath := "/session/" + session.SessionID
+	defer driver.call(http.MethodDelete, sessionPath, nil, nil)
+
+	if width, height, ok := parseResolution(firefox.Resolution); ok {
+		if err := driver.call(http.MethodPost, sessionPath+"/window/rect", map[string]int{"width": width, "height": height}, nil); err != nil {
+			return err
+		}
+	}
+	if err := driver.call(http.MethodPost, sessionPath+"/url", map[string]string{"url": targetURL}, nil); err != nil {
+		return errors.Wrap(err, "loading page")
+	}
+
+	return use(driver, sessionPath)
+}
+
+type webDriver struct {
+	ctx  context.Context
+	base string
+}
+
+// waitReady polls the status endpoint until geckodriver accepts sessions.
+func (driver *webDriver) waitReady() error {
+	for {
+		var status struct {
+			Ready bool `json:"ready"`
+		}
+		if err := driver.call(http.MethodGet, "/status", nil, &status); err == nil && status.Ready {
+			return nil
+		}
+		select {
+		case <-driver.ctx.Done():
+			return errors.New("geckodriver did not start in time")
+		case <-time.After(100 * time.Millisecond):
+		}
+	}
+}
+
+// call sends a WebDriver command and decodes the value of the response into value.
+func (driver *webDriver) call(method, path string, body interface{}, value interface{}) error {
+	var payload []byte
+	if body != nil {
+		var err error
+		if payload, err = json.Marshal(body); err != nil {
+			return err
+		}
+	}
+	req, err := http.NewRequestWithContext(driver.ctx, method, driver.base+path, bytes.NewReader(payload))
+	if err != nil {
+		return err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	res, err := http.DefaultClient.Do(req)
+	if err != nil {
+		return err
+	}
+	defer res.Body.Close()
+
+	var response struct {
+		Value json.RawMessage `json:"value"`
+	}
+	if err := json.NewDecoder(res.Body).Decode(&response); err != nil {
+		return err
+	}
+	if res.StatusCode != http.StatusOK {
+		var failure struct {
+			Error   string `json:"error"`
+			Message string `json:"message"`
+		}
+		json.Unmarshal(response.Value, &failure)
+		return fmt.Errorf("%s: %s", failure.Error, failure.Message)
+	}
+	if value != nil {
+		return json.Unmarshal(response.Value, value)
+	}
+	return nil
+}
+
+func freePort() (int, error) {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		return 0, err
+	}
+	defer listener.Close()
+	return listener.Addr().(*net.TCPAddr).Port, nil
+}
+
+// parseResolution parses a resolution such as 1024x768 or 1024,768.
+func parseResolution(resolution string) (int, int, bool) {
+	parts := strings.FieldsFunc(resolution, func(r rune) bool { return r == 'x' || r == ',' })
+	if len(parts) != 2 {
+		return 0, 0, false
+	}
+	width, err := strconv.Atoi(strings.TrimSpace(parts[0]))
+	if err != nil {
+		return 0, 0, false
+	}
+	height, err := strconv.Atoi(strings.TrimSpace(parts[1]))
+	if err != nil {
+		return 0, 0, false
+	}
+	return width, height, true
+}
+
+// Available tells whether a screenshot could be taken, for a check before the scan.
+func Available(path string) bool {
+	if path != "" {
+		_, err := os.Stat(path)
+		return err == nil
+	}
+	_, err := exec.LookPath("geckodriver")
+	return err == nil
+}
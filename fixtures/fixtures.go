@@ -0,0 +1,194 @@
+package fixtures
+
+import (
+	"bytes"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"io/ioutil"
+	"net/http"
+	"net/url"
+	"os"
+	"path/filepath"
+	"strings"
+	"sync"
+	"unicode/utf8"
+)
+
+// Response is a recorded response, as decompressed.
+type Response struct {
+	Status int `json:"status"`
+	// Url of the last response when the site redirected.
+	FinalURL string      `json:"final_url,omitempty"`
+	Header   http.Header `json:"header,omitempty"`
+	Body     string      `json:"body,omitempty"`
+	// Body of the responses that are not text, such as avatars.
+	BinaryBody []byte `json:"binary_body,omitempty"`
+}
+
+// Exchange is a request and the response it got.
+type Exchange struct {
+	Method   string   `json:"method"`
+	URL      string   `json:"url"`
+	Body     string   `json:"body,omitempty"`
+	Response Response `json:"response"`
+}
+
+// ErrNotRecorded is returned when a request has no recorded response.
+var ErrNotRecorded = errors.New("no recorded response")
+
+// Store keeps the exchanges with each host in a json file of its directory named after the host,
+// so the responses of a site can be recorded again, reviewed or edited on their own.
+type Store struct {
+	dir     string
+	mutex   sync.Mutex
+	hosts   map[string][]Exchange
+	changed map[string]bool
+}
+
+// Open returns the store of the directory. The files of the hosts are read when first needed.
+func Open(dir string) *Store {
+	return &Store{dir: dir, hosts: map[string][]Exchange{}, changed: map[string]bool{}}
+}
+
+func (store *Store) fileName(host string) string {
+	return filepath.Join(store.dir, strings.Replace(strings.ToLower(host), ":", "_", -1)+".json")
+}
+
+// exchanges returns the exchanges with the host, with the mutex held.
+func (store *Store) exchanges(host string) []Exchange {
+	if exchanges, ok := store.hosts[host]; ok {
+		return exchanges
+	}
+	var exchanges []Exchange
+	if data, err := ioutil.ReadFile(store.fileName(host)); err == nil {
+		if err := json.Unmarshal(data, &exchanges); err != nil {
+			exchanges = nil
+		}
+	}
+	store.hosts[host] = exchanges
+	return exchanges
+}
+
+// HasRequest tells whether a response to a request without body of method to target was recorded.
+func (store *Store) HasRequest(method, target string) bool {
+	parsed, err := url.Parse(target)
+	if err != nil {
+		return false
+	}
+	store.mutex.Lock()
+	defer store.mutex.Unlock()
+	for _, exchange := range store.exchanges(strings.ToLower(parsed.Host)) {
+		if exchange.Method == method && exchange.URL == parsed.String() && exchange.Body == "" {
+			return true
+		}
+	}
+	return false
+}
+
+// Replay returns the recorded response of the request.
+func (store *Store) Replay(request *http.Request, body string) (*http.Response, error) {
+	store.mutex.Lock()
+	defer store.mutex.Unlock()
+	target := request.URL.String()
+	for _, exchange := range store.exchanges(strings.ToLower(request.URL.Host)) {
+		if exchange.Method != request.Method || exchange.URL != target || exchange.Body != body {
+			continue
+		}
+		recorded := exchange.Response
+		data := recorded.BinaryBody
+		if data == nil {
+			data = []byte(recorded.Body)
+		}
+		final := request
+		if recorded.FinalURL != "" {
+			finalRequest, err := http.NewRequestWithContext(request.Context(), request.Method, recorded.FinalURL, nil)
+			if err != nil {
+				return nil, err
+			}
+			final = finalRequest
+		}
+		header := recorded.Header.Clone()
+		if header == nil {
+			header = http.Header{}
+		}
+		return &http.Response{
+			Status:        fmt.Sprintf("%d %s", recorded.Status, http.StatusText(recorded.Status)),
+			StatusCode:    recorded.Status,
+			Proto:         "HTTP/1.1",
+			ProtoMajor:    1,
+			ProtoMinor:    1,
+			Header:        header,
+			Body:          ioutil.NopCloser(bytes.NewReader(data)),
+			ContentLength: int64(len(data)),
+			Request:       final,
+		}, nil
+	}
+	return nil, fmt.Errorf("%w for %s %s", ErrNotRecorded, request.Method, target)
+}
+
+// Record reads the response of the request into the store and returns it with its body to be
+// read again. The cookies the site set are not kept.
+func (store *Store) Record(request *http.Request, body string, response *http.Response) (*http.Response, error) {
+	data, err := ioutil.ReadAll(response.Body)
+	response.Body.Close()
+	if err != nil {
+		return nil, err
+	}
+	response.Body = ioutil.NopCloser(bytes.NewReader(data))
+
+	header := response.Header.Clone()
+	header.Del("Set-Cookie")
+	recorded := Response{Status: response.StatusCode, Header: header}
+	if final := response.Request.URL.String(); final != request.URL.String() {
+		recorded.FinalURL = final
+	}
+	if utf8.Valid(data) {
+		recorded.Body = string(data)
+	} else {
+		recorded.BinaryBody = data
+	}
+	exchange := Exchange{Method: request.Method, URL: request.URL.String(), Body: body, Response: recorded}
+
+	store.mutex.Lock()
+	defer store.mutex.Unlock()
+	host := strings.ToLower(request.URL.Host)
+	exchanges := store.exchanges(host)
+	// The last response replaces an earlier one, e.g. of a retry.
+	replaced := false
+	for i, earlier := range exchanges {
+		if earlier.Method == exchange.Method && earlier.URL == exchange.URL && earlier.Body == exchange.Body {
+			exchanges[i] = exchange
+			replaced = true
+		}
+	}
+	if !replaced {
+		exchanges = append(exchanges, exchange)
+	}
+	store.hosts[host] = exchanges
+	store.changed[host] = true
+	return response, nil
+}
+
+// Save writes the files of the hosts that got new responses.
+func (store *Store) Save() error {
+	store.mutex.Lock()
+	defer store.mutex.Unlock()
+	if len(store.changed) == 0 {
+		return nil
+	}
+	if err := os.MkdirAll(store.dir, 0755); err != nil {
+		return err
+	}
+	for host := range store.changed {
+		data, err := json.MarshalIndent(store.hosts[host], "", "  ")
+		if err != nil {
+			return err
+		}
+		if err := ioutil.WriteFile(store.fileName(host), data, 0644); err != nil {
+			return err
+		}
+	}
+	store.changed = map[string]bool{}
+	return nil
+}
@@ -0,0 +1,79 @@
+package flaresolverr
+
+import (
+	"bytes"
+	"context"
+	"encoding/json"
+	"fmt"
+	"io/ioutil"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+type request struct {
+	Cmd        string `json:"cmd"`
+	URL        string `json:"url"`
+	MaxTimeout int64  `json:"maxTimeout"`
+}
+
+type response struct {
+	Status   string `json:"status"`
+	Message  string `json:"message"`
+	Solution struct {
+		URL      string            `json:"url"`
+		Status   int               `json:"status"`
+		Headers  map[string]string `json:"headers"`
+		Response string            `json:"response"`
+	} `json:"solution"`
+}
+
+// Get has the FlareSolverr instance at endpoint load target in its browser, passing the Cloudflare
+// challenge, and returns the page as if it had been requested directly. The request of the
+// response carries the final url after redirects.
+func Get(ctx context.Context, endpoint, target string, timeout time.Duration) (*http.Response, error) {
+	payload, err := json.Marshal(request{Cmd: "request.get", URL: target, MaxTimeout: timeout.Milliseconds()})
+	if err != nil {
+		return nil, err
+	}
+
+	req, err := http.NewRequestWithContext(ctx, http.MethodPost, strings.TrimRight(endpoint, "/")+"/v1", bytes.NewReader(payload))
+	if err != nil {
+		return nil, err
+	}
+	req.Header.Set("Content-Type", "application/json")
+	// FlareSolverr answers once its browser is done, give it some time on top of its own timeout.
+	client := &http.Client{Timeout: timeout + 10*time.Second}
+	res, err := client.Do(req)
+	if err != nil {
+		return nil, errors.Wrap(err, "FlareSolverr")
+	}
+	defer res.Body.Close()
+
+	var solved response
+	if err := json.NewDecoder(res.Body).Decode(&solved); err != nil {
+		return nil, errors.Wrap(err, "FlareSolverr")
+	}
+	if solved.Status != "ok" {
+		return nil, fmt.Errorf("FlareSolverr: %s", solved.Message)
+	}
+
+	finalURL, err := url.Parse(solved.Solution.URL)
+	if err != nil {
+		return nil, err
+	}
+	header := http.Header{}
+	for name, value := range solved.Solution.Headers {
+		header.Set(name, value)
+	}
+	return &http.Response{
+		Status:     fmt.Sprintf("%d %s", solved.Solution.Status, http.StatusText(solved.Solution.Status)),
+		StatusCode: solved.Solution.Status,
+		Header:     header,
+		Body:       ioutil.NopCloser(strings.NewReader(solved.Solution.Response)),
+		Request:    &http.Request{Method: http.MethodGet, URL: finalURL, Header: http.Header{}},
+	}, nil
+}
@@ -0,0 +1,95 @@
+package identifier
+
+import (
+	"net/url"
+	"regexp"
+	"strings"
+)
+
+// Types of identifiers.
+const (
+	Username = "username"
+	Email    = "email"
+	Phone    = "phone"
+	URL      = "url"
+)
+
+// Types lists every type, for --id-type.
+var Types = []string{Username, Email, Phone, URL}
+
+var (
+	emailPattern = regexp.MustCompile(`^[^@\s]+@[^@\s]+\.[^@\s]+$`)
+	phonePattern = regexp.MustCompile(`^\+?[0-9][0-9 ().-]{6,}[0-9]$`)
+	// Bare digits are as likely a numeric username, only a leading + or separators tell a phone.
+	phoneMarkers = regexp.MustCompile(`^\+|[ ().-]`)
+)
+
+// Detect tells what kind of identifier the input is. Anything that is not clearly an email, a
+// phone number or a url is a username.
+func Detect(input string) string {
+	input = strings.TrimSpace(input)
+	switch {
+	case emailPattern.MatchString(input):
+		return Email
+	case phonePattern.MatchString(input) && phoneMarkers.MatchString(input) && Digits(input) >= 7:
+		return Phone
+	case isURL(input):
+		return URL
+	}
+	return Username
+}
+
+// Digits counts the digits of a phone number.
+func Digits(input string) int {
+	var digits int
+	for _, r := range input {
+		if r >= '0' && r <= '9' {
+			digits++
+		}
+	}
+	return digits
+}
+
+func isURL(input string) bool {
+	parsed, err := url.Parse(input)
+	if err != nil {
+		return false
+	}
+	return (parsed.Scheme == "http" || parsed.Scheme == "https") && parsed.Host != ""
+}
+
+// UsernameFromURL returns the username of a profile url matching one of the url templates, with
+// {} in place of the username.
+func UsernameFromURL(profileURL string, templates []string) (string, bool) {
+	normalized := normalizeURL(profileURL)
+	for _, template := range templates {
+		parts := strings.SplitN(normalizeURL(template), "{}", 2)
+		if len(parts) != 2 || !strings.HasPrefix(strings.ToLower(normalized), strings.ToLower(parts[0])) {
+			continue
+		}
+		rest := normalized[len(parts[0]):]
+		if !strings.HasSuffix(strings.ToLower(rest), strings.ToLower(parts[1])) {
+			continue
+		}
+		username, err := url.PathUnescape(rest[:len(rest)-len(parts[1])])
+		if err != nil {
+			continue
+		}
+		// Checked once unescaped, ..%2F.. is a path too.
+		if username != "" && username != "." && username != ".." && !strings.ContainsAny(username, "/?#\\") {
+			return username, true
+		}
+	}
+	return "", false
+}
+
+// normalizeURL drops the scheme, www. and trailing slashes, so the same profile matches however
+// it was written.
+func normalizeURL(raw string) string {
+	raw = strings.TrimSpace(raw)
+	if i := strings.Index(raw, "://"); i >= 0 {
+		raw = raw[i+3:]
+	}
+	raw = strings.TrimPrefix(raw, "www.")
+	return strings.TrimRight(raw, "/")
+}
@@ -0,0 +1,154 @@
+package intelx
+
+import (
+	"context"
+	"encoding/json"
+	"errors"
+	"fmt"
+	"net/http"
+	"net/url"
+	"strings"
+	"time"
+)
+
+// Config is the IntelligenceX account of the leak search.
+type Config struct {
+	APIKey string `json:"api_key"`
+	// API of the account's plan, https://free.intelx.io by default.
+	URL string `json:"url"`
+	// Most documents kept per searched term, 20 by default.
+	MaxResults int `json:"max_results"`
+}
+
+// Mention is a paste, leak or other document of the IntelligenceX archive mentioning a term.
+type Mention struct {
+	Term   string    `json:"term"`
+	Name   string    `json:"name"`
+	Date   time.Time `json:"date"`
+	Bucket string    `json:"bucket"`
+	Media  string    `json:"media"`
+	URL    string    `json:"url"`
+}
+
+// Send makes a request through the proxies of the scan.
+type Send func(ctx context.Context, method, target, body string, headers http.Header) (*http.Response, error)
+
+// Client searches the archive of an IntelligenceX account.
+type Client struct {
+	endpoint   string
+	apiKey     string
+	maxResults int
+	send       Send
+}
+
+// NewClient checks the config and returns its client, whose requests are made with send.
+func NewClient(config Config, send Send) (*Client, error) {
+	if config.APIKey == "" {
+		return nil, errors.New("intelx needs an api_key")
+	}
+	client := &Client{
+		endpoint:   strings.TrimRight(config.URL, "/"),
+		apiKey:     config.APIKey,
+		maxResults: config.MaxResults,
+		send:       send,
+	}
+	if client.endpoint == "" {
+		client.endpoint = "https://free.intelx.io"
+	}
+	if client.maxResults <= 0 {
+		client.maxResults = 20
+	}
+	return client, nil
+}
+
+const maxPolls = 30
+
+type record struct {
+	SystemID string `json:"systemid"`
+	Name     string `json:"name"`
+	Date     string `json:"date"`
+	Bucket   string `json:"bucketh"`
+	Media    string `json:"mediah"`
+}
+
+// Search returns the documents mentioning the term, newest first. IntelligenceX searches
+// asynchronously, the results are polled for up to maxPolls seconds.
+func (client *Client) Search(ctx context.Context, term string) ([]Mention, error) {
+	var started struct {
+		ID     string `json:"id"`
+		Status int    `json:"status"`
+	}
+	err := client.call(ctx, http.MethodPost, "/intelligent/search", map[string]interface{}{
+		"term":       term,
+		"maxresults": client.maxResults,
+		"media":      0,
+		"sort":       4,
+		"terminate":  []string{},
+		"timeout":    0,
+	}, &started)
+	if err != nil {
+		return nil, err
+	}
+	if started.Status != 0 {
+		return nil, fmt.Errorf("intelx refused the search of %s (status %d)", term, started.Status)
+	}
+
+	var mentions []Mention
+	for poll := 0; poll < maxPolls; poll++ {
+		var results struct {
+			Records []record `json:"records"`
+			// 0 more results are coming, 1 done, 2 unknown search, 3 no results yet.
+			Status int `json:"status"`
+		}
+		query := url.Values{"id": {started.ID}, "limit": {fmt.Sprint(client.maxResults)}}
+		if err := client.call(ctx, http.MethodGet, "/intelligent/search/result?"+query.Encode(), nil, &results); err != nil {
+			return mentions, err
+		}
+		for _, r := range results.Records {
+			date, _ := time.Parse("2006-01-02T15:04:05Z", r.Date)
+			mentions = append(mentions, Mention{
+				Term:   term,
+				Name:   r.Name,
+				Date:   date,
+				Bucket: r.Bucket,
+				Media:  r.Media,
+				URL:    "https://intelx.io/?did=" + url.QueryEscape(r.SystemID),
+			})
+		}
+		if results.Status == 1 || results.Status == 2 || len(mentions) >= client.maxResults {
+			break
+		}
+		select {
+		case <-ctx.Done():
+			return mentions, ctx.Err()
+		case <-time.After(time.Second):
+		}
+	}
+	if len(mentions) > client.maxResults {
+		mentions = mentions[:client.maxResults]
+	}
+	return mentions, nil
+}
+
+func (client *Client) call(ctx context.Context, method, path string, body interface{}, response interface{}) error {
+	var payload []byte
+	if body != nil {
+		var err error
+		if payload, err = json.Marshal(body); err != nil {
+			return err
+		}
+	}
+	headers := http.Header{"X-Key": {client.apiKey}}
+	if body != nil {
+		headers.Set("Content-Type", "application/json")
+	}
+	res, err := client.send(ctx, method, client.endpoint+path, string(payload), headers)
+	if err != nil {
+		return err
+	}
+	defer res.Body.Close()
+	if res.StatusCode != http.StatusOK {
+		return fmt.Errorf("intelx answered %s", res.Status)
+	}
+	return json.NewDecoder(res.Body).Decode(response)
+}
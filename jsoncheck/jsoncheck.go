@@ -0,0 +1,92 @@
+package jsoncheck
+
+import (
+	"encoding/json"
+	"errors"
+	"fmt"
+	"reflect"
+	"regexp"
+	"strings"
+
+	"github.com/tidwall/gjson"
+)
+
+// A condition is a gjson path, optionally with a leading $. as in JSONPath, and an optional
+// comparison with a JSON value, such as "$.data.user != null" or "found == true". A path without
+// a comparison holds when its value exists and is not null, false, 0, "" or empty.
+var conditionPattern = regexp.MustCompile(`^(.+?)(?:\s*(==|!=)\s*(.+))?$`)
+
+// Array indexes of JSONPath, [0] is .0 in gjson.
+var indexPattern = regexp.MustCompile(`\[(\d+)\]`)
+
+// Condition is a parsed condition of a site of the json error type.
+type Condition struct {
+	path     string
+	operator string
+	value    interface{}
+}
+
+// Parse reads a condition, see conditionPattern.
+func Parse(condition string) (Condition, error) {
+	match := conditionPattern.FindStringSubmatch(strings.TrimSpace(condition))
+	if match == nil {
+		return Condition{}, errors.New("empty json condition")
+	}
+	path := strings.TrimPrefix(strings.TrimSpace(match[1]), "$")
+	path = strings.TrimPrefix(indexPattern.ReplaceAllString(path, ".$1"), ".")
+	parsed := Condition{path: path, operator: match[2]}
+	if parsed.path == "" {
+		return Condition{}, fmt.Errorf("json condition %q has no path", condition)
+	}
+	if parsed.operator != "" {
+		if err := json.Unmarshal([]byte(match[3]), &parsed.value); err != nil {
+			return Condition{}, fmt.Errorf("json condition %q compares with an invalid value: %s", condition, err)
+		}
+	}
+	return parsed, nil
+}
+
+// Match evaluates the condition on a JSON document.
+func (condition Condition) Match(body []byte) (bool, error) {
+	if !gjson.ValidBytes(body) {
+		return false, errors.New("the response is not json")
+	}
+	result := gjson.GetBytes(body, condition.path)
+	switch condition.operator {
+	case "==":
+		return equal(result, condition.value), nil
+	case "!=":
+		return !equal(result, condition.value), nil
+	default:
+		return truthy(result), nil
+	}
+}
+
+func truthy(result gjson.Result) bool {
+	switch {
+	case result.IsArray():
+		return len(result.Array()) > 0
+	case result.IsObject():
+		return len(result.Map()) > 0
+	}
+	switch result.Type {
+	case gjson.Number:
+		return result.Num != 0
+	case gjson.String:
+		return result.Str != ""
+	default:
+		// Missing values are null.
+		return result.Type == gjson.True
+	}
+}
+
+func equal(result gjson.Result, value interface{}) bool {
+	if !result.Exists() || result.Type == gjson.Null {
+		return value == nil
+	}
+	var actual interface{}
+	if err := json.Unmarshal([]byte(result.Raw), &actual); err != nil {
+		return false
+	}
+	return reflect.DeepEqual(actual, value)
+}
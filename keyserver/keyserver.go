@@ -0,0 +1,148 @@
+package keyserver
+
+import (
+	"bufio"
+	"bytes"
+	"net/url"
+	"regexp"
+	"sort"
+	"strconv"
+	"strings"
+	"time"
+)
+
+// Fetch returns the body of a url, so the lookups go through the same proxies as the checks.
+// Keyservers answer 404 when nothing matches, which Fetch reports as an error.
+type Fetch func(target string) ([]byte, error)
+
+// Key is a public key whose user ids matched a username or an email.
+type Key struct {
+	KeyID   string     `json:"key_id"`
+	Created time.Time  `json:"created"`
+	Expires *time.Time `json:"expires,omitempty"`
+	UIDs    []string   `json:"uids"`
+	Emails  []string   `json:"emails,omitempty"`
+	Servers []string   `json:"servers"`
+	// The username or emails the key was found by.
+	Queries []string `json:"queries"`
+}
+
+// Servers searched. keys.openpgp.org only finds the verified email of a key, keyserver.ubuntu.com
+// also finds the words of user ids, such as a username.
+var servers = []struct {
+	name      string
+	url       string
+	emailOnly bool
+}{
+	{name: "keys.openpgp.org", url: "https://keys.openpgp.org/pks/lookup", emailOnly: true},
+	{name: "keyserver.ubuntu.com", url: "https://keyserver.ubuntu.com/pks/lookup"},
+}
+
+// Most keys kept per search, a common word matches thousands.
+const maxKeys = 20
+
+var emailPattern = regexp.MustCompile(`<([^<>@\s]+@[^<>@\s]+)>`)
+
+// Lookup searches the keyservers for the username and the emails and returns the matching keys,
+// newest first.
+func Lookup(username string, emails []string, fetch Fetch) []Key {
+	keys := map[string]*Key{}
+	for _, server := range servers {
+		queries := emails
+		if !server.emailOnly {
+			queries = append([]string{username}, emails...)
+		}
+		for _, query := range queries {
+			body, err := fetch(server.url + "?" + url.Values{
+				"op":      {"index"},
+				"options": {"mr"},
+				"search":  {query},
+			}.Encode())
+			if err != nil {
+				continue
+			}
+			for _, found := range parseIndex(body) {
+				key, ok := keys[found.KeyID]
+				if !ok {
+					found := found
+					key = &found
+					keys[found.KeyID] = key
+				}
+				if !contains(key.Servers, server.name) {
+					key.Servers = append(key.Servers, server.name)
+				}
+				if !contains(key.Queries, query) {
+					key.Queries = append(key.Queries, query)
+				}
+			}
+		}
+	}
+
+	var found []Key
+	for _, key := range keys {
+		found = append(found, *key)
+	}
+	sort.Slice(found, func(i, j int) bool {
+		return found[i].Created.After(found[j].Created)
+	})
+	return found
+}
+
+// parseIndex reads the machine readable index of HKP, a pub line for every key followed by its
+// uid lines.
+func parseIndex(body []byte) []Key {
+	var keys []Key
+	scanner := bufio.NewScanner(bytes.NewReader(body))
+	for scanner.Scan() {
+		fields := strings.Split(strings.TrimSpace(scanner.Text()), ":")
+		switch fields[0] {
+		case "pub":
+			if len(keys) == maxKeys || len(fields) < 2 {
+				return keys
+			}
+			key := Key{KeyID: strings.ToUpper(fields[1])}
+			if len(fields) > 4 {
+				key.Created = unixTime(fields[4])
+			}
+			if len(fields) > 5 {
+				if expires := unixTime(fields[5]); !expires.IsZero() {
+					key.Expires = &expires
+				}
+			}
+			keys = append(keys, key)
+		case "uid":
+			if len(keys) == 0 || len(fields) < 2 {
+				continue
+			}
+			uid, err := url.PathUnescape(fields[1])
+			if err != nil {
+				uid = fields[1]
+			}
+			key := &keys[len(keys)-1]
+			key.UIDs = append(key.UIDs, uid)
+			if match := emailPattern.FindStringSubmatch(uid); match != nil {
+				if !contains(key.Emails, strings.ToLower(match[1])) {
+					key.Emails = append(key.Emails, strings.ToLower(match[1]))
+				}
+			}
+		}
+	}
+	return keys
+}
+
+func unixTime(field string) time.Time {
+	seconds, err := strconv.ParseInt(field, 10, 64)
+	if err != nil || seconds == 0 {
+		return time.Time{}
+	}
+	return time.Unix(seconds, 0).UTC()
+}
+
+func contains(values []string, value string) bool {
+	for _, v := range values {
+		if v == value {
+			return true
+		}
+	}
+	return false
+}
@@ -0,0 +1,97 @@
+package main
+
+import (
+	"encoding/json"
+	"io/ioutil"
+	"net/url"
+	"path/filepath"
+	"testing"
+
+	"github.com/krishpranav/maigret/fixtures"
+)
+
+// TestFixtures checks the sites of data.json against the responses recorded in testdata/fixtures,
+// as maigret --test --offline --fixtures testdata/fixtures does.
+func TestFixtures(t *testing.T) {
+	data, err := ioutil.ReadFile("data.json")
+	if err != nil {
+		t.Fatal(err)
+	}
+	if err := json.Unmarshal(data, &siteData); err != nil {
+		t.Fatal(err)
+	}
+	applyEngines()
+	options.offline = true
+	fixtureStore = fixtures.Open(filepath.Join("testdata", "fixtures"))
+	defer func() {
+		options.offline = false
+		fixtureStore = nil
+	}()
+
+	sites := recordedSites(siteData)
+	// A site whose urls changed is no longer recorded and would silently go untested.
+	for _, site := range []string{"Docker Hub", "GitHub", "GitLab", "HackerNews"} {
+		if _, ok := sites[site]; !ok {
+			t.Errorf("%s: the probes of its usernames are not in testdata/fixtures", site)
+		}
+	}
+	for site, data := range sites {
+		used := maigret(data.UsedUsername, site, data)
+		unused := maigret(data.UnusedUsername, site, data)
+		if used.Err || unused.Err {
+			t.Errorf("%s: failed with error [%s][%s]", site, used.ErrMsg, unused.ErrMsg)
+			continue
+		}
+		if !used.Exist {
+			t.Errorf("%s: %s was not found", site, data.UsedUsername)
+		}
+		if unused.Exist {
+			t.Errorf("%s: %s was found", site, data.UnusedUsername)
+		}
+	}
+}
+
+func TestSubstituteUsername(t *testing.T) {
+	tests := []struct {
+		template string
+		username string
+		raw      bool
+		want     string
+	}{
+		{"https://github.com/{}", "blue", false, "https://github.com/blue"},
+		{"https://github.com/{}", "jürgen", false, "https://github.com/j%C3%BCrgen"},
+		{"https://twitter.com/{}", "名前", false, "https://twitter.com/%E5%90%8D%E5%89%8D"},
+		{"https://vk.com/{}", "иван", false, "https://vk.com/%D0%B8%D0%B2%D0%B0%D0%BD"},
+		{"https://news.ycombinator.com/user?id={}", "john doe", false, "https://news.ycombinator.com/user?id=john%20doe"},
+		{"https://example.com/u/{}", "c#", false, "https://example.com/u/c%23"},
+		{"https://example.com/u/{}", "who?", false, "https://example.com/u/who%3F"},
+		{"https://example.com/u/{}", "a/b&c=d", false, "https://example.com/u/a%2Fb%26c%3Dd"},
+		{"https://example.com/u/{}", "a-b.c_d~e", false, "https://example.com/u/a-b.c_d~e"},
+		// Usernames in the host and of sites expecting raw values are left as they are.
+		{"https://{}.tumblr.com/", "blue", false, "https://blue.tumblr.com/"},
+		{"https://example.com/search?q={}", "john doe", true, "https://example.com/search?q=john doe"},
+	}
+	for _, test := range tests {
+		if got := substituteUsername(test.template, test.username, test.raw); got != test.want {
+			t.Errorf("substituteUsername(%q, %q, %t) = %q, want %q", test.template, test.username, test.raw, got, test.want)
+		}
+	}
+}
+
+func TestEscapeUsername(t *testing.T) {
+	for _, username := range []string{"jürgen", "名前", "😀", "john doe", "c#", "who?", "100%"} {
+		escaped := escapeUsername(username)
+		// The escaped username is a single path segment that decodes back to the username.
+		parsed, err := url.Parse("https://example.com/" + escaped)
+		if err != nil {
+			t.Errorf("escapeUsername(%q) = %q, which does not parse: %s", username, escaped, err)
+			continue
+		}
+		if parsed.Path != "/"+username || parsed.RawQuery != "" || parsed.Fragment != "" {
+			t.Errorf("escapeUsername(%q) = %q, which parses to path %q", username, escaped, parsed.Path)
+		}
+		if parsed.String() != "https://example.com/"+escaped {
+			t.Errorf("escapeUsername(%q) = %q, which is not kept as is in the url", username, escaped)
+		}
+	}
+}
@@ -0,0 +1,62 @@
+package notify
+
+import (
+	"encoding/json"
+	"fmt"
+	"strings"
+)
+
+const (
+	maxLinks          int = 10
+	discordContentMax int = 2000
+)
+
+type Summary struct {
+	Username string
+	Checked  int
+	Found    int
+	Links    []string
+}
+
+func formatSummaries(summaries []Summary, bold string) string {
+	var builder strings.Builder
+	for _, summary := range summaries {
+		fmt.Fprintf(&builder, "%s%s%s: %d found on %d sites\n", bold, summary.Username, bold, summary.Found, summary.Checked)
+		for i, link := range summary.Links {
+			if i == maxLinks {
+				fmt.Fprintf(&builder, "... and %d more\n", len(summary.Links)-maxLinks)
+				break
+			}
+			fmt.Fprintf(&builder, "- %s\n", link)
+		}
+	}
+	return builder.String()
+}
+
+// Text formats the scan summaries as plain text.
+func Text(summaries []Summary) string {
+	return formatSummaries(summaries, "")
+}
+
+// Slack posts the scan summaries to a Slack incoming webhook.
+func Slack(target string, summaries []Summary) error {
+	payload, err := json.Marshal(map[string]string{"text": formatSummaries(summaries, "*")})
+	if err != nil {
+		return err
+	}
+	return post(target, "application/json", payload)
+}
+
+// Discord posts the scan summaries to a Discord webhook.
+func Discord(target string, summaries []Summary) error {
+	content := formatSummaries(summaries, "**")
+	// Discord counts characters, and rejects content cut in the middle of one.
+	if runes := []rune(content); len(runes) > discordContentMax {
+		content = string(runes[:discordContentMax-3]) + "..."
+	}
+	payload, err := json.Marshal(map[string]string{"content": content})
+	if err != nil {
+		return err
+	}
+	return post(target, "application/json", payload)
+}
@@ -0,0 +1,31 @@
+package notify
+
+import (
+	"bytes"
+	"net/http"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+var client = &http.Client{
+	Timeout: 30 * time.Second,
+}
+
+func post(target string, contentType string, payload []byte) error {
+	r, err := client.Post(target, contentType, bytes.NewReader(payload))
+	if err != nil {
+		return err
+	}
+	defer r.Body.Close()
+
+	if r.StatusCode < 200 || r.StatusCode >= 300 {
+		return errors.Errorf("%s responded with %s", target, r.Status)
+	}
+	return nil
+}
+
+// Webhook posts a json payload to a user supplied endpoint.
+func Webhook(target string, payload []byte) error {
+	return post(target, "application/json", payload)
+}
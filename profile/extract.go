@@ -0,0 +1,101 @@
+package profile
+
+import (
+	"net/url"
+	"regexp"
+	"strings"
+
+	"golang.org/x/net/html"
+)
+
+// Profile holds the attributes of a profile page that help to tell whether two accounts belong
+// to the same person.
+type Profile struct {
+	Name   string   `json:"name,omitempty"`
+	Avatar string   `json:"avatar,omitempty"`
+	Links  []string `json:"links,omitempty"`
+	// Emails are only known for the sites with an extractor, such as the package registries.
+	Emails []string `json:"emails,omitempty"`
+}
+
+var linkPattern = regexp.MustCompile(`https?://[^\s"'<>]+`)
+
+// Empty tells whether nothing was found on the page.
+func (profile Profile) Empty() bool {
+	return profile.Name == "" && profile.Avatar == "" && len(profile.Links) == 0 && len(profile.Emails) == 0
+}
+
+// Extract reads the display name and avatar from the Open Graph and Twitter card tags of a page.
+// Links are the rel="me" links and the urls in the description of the page.
+func Extract(body, pageURL string) Profile {
+	var profile Profile
+	base, _ := url.Parse(pageURL)
+	seen := map[string]bool{}
+	addLink := func(link string) {
+		if resolved := resolve(base, link); resolved != "" && !seen[resolved] && resolved != pageURL {
+			seen[resolved] = true
+			profile.Links = append(profile.Links, resolved)
+		}
+	}
+
+	tokenizer := html.NewTokenizer(strings.NewReader(body))
+	for {
+		tokenType := tokenizer.Next()
+		if tokenType == html.ErrorToken {
+			break
+		}
+		if tokenType != html.StartTagToken && tokenType != html.SelfClosingTagToken {
+			continue
+		}
+
+		token := tokenizer.Token()
+		attributes := map[string]string{}
+		for _, attribute := range token.Attr {
+			attributes[strings.ToLower(attribute.Key)] = attribute.Val
+		}
+
+		switch token.Data {
+		case "meta":
+			name := attributes["property"]
+			if name == "" {
+				name = attributes["name"]
+			}
+			content := strings.TrimSpace(attributes["content"])
+			switch strings.ToLower(name) {
+			case "og:title", "twitter:title":
+				if profile.Name == "" {
+					profile.Name = content
+				}
+			case "og:image", "twitter:image":
+				if profile.Avatar == "" {
+					profile.Avatar = resolve(base, content)
+				}
+			case "og:description", "description", "twitter:description":
+				for _, link := range linkPattern.FindAllString(content, -1) {
+					addLink(link)
+				}
+			}
+		case "a", "link":
+			for _, rel := range strings.Fields(attributes["rel"]) {
+				if strings.ToLower(rel) == "me" {
+					addLink(attributes["href"])
+				}
+			}
+		}
+	}
+	return profile
+}
+
+func resolve(base *url.URL, link string) string {
+	parsed, err := url.Parse(strings.TrimSpace(link))
+	if err != nil || link == "" {
+		return ""
+	}
+	if base != nil {
+		parsed = base.ResolveReference(parsed)
+	}
+	if parsed.Scheme != "http" && parsed.Scheme != "https" {
+		return ""
+	}
+	return parsed.String()
+}
@@ -0,0 +1,109 @@
+package proxies
+
+import (
+	"strings"
+
+	"github.com/pkg/errors"
+)
+
+// Rule routes the sites of a host through a proxy.
+type Rule struct {
+	// Host suffix such as .ru or instagram.com, * matches every host.
+	Match string `json:"match"`
+	// direct, the name of a pool in proxy_pools, or a proxy url.
+	Via string `json:"via"`
+}
+
+// Router picks the pool of a host by the first matching rule, hosts without a rule use the
+// default pool.
+type Router struct {
+	rules    []Rule
+	pools    map[string]*Pool
+	fallback *Pool
+}
+
+// NewRouter builds the named pools and the pool of every proxy url used by a rule.
+func NewRouter(rules []Rule, pools map[string][]string, fallback []string) (*Router, error) {
+	router := &Router{rules: rules, pools: map[string]*Pool{}}
+
+	var err error
+	if router.fallback, err = NewPool(fallback); err != nil {
+		return nil, err
+	}
+	for name, addresses := range pools {
+		if router.pools[name], err = NewPool(addresses); err != nil {
+			return nil, errors.Wrapf(err, "proxy pool %q", name)
+		}
+	}
+
+	for _, rule := range rules {
+		if rule.Match == "" {
+			return nil, errors.New("proxy rule without match")
+		}
+		if _, ok := router.pools[rule.Via]; ok || rule.Via == "direct" {
+			continue
+		}
+		if !strings.Contains(rule.Via, "://") {
+			return nil, errors.Errorf("proxy rule for %q uses the unknown pool %q", rule.Match, rule.Via)
+		}
+		if router.pools[rule.Via], err = NewPool([]string{rule.Via}); err != nil {
+			return nil, err
+		}
+	}
+	return router, nil
+}
+
+// Route returns the pool for the host, nil means a direct connection.
+func (router *Router) Route(host string) *Pool {
+	_, pool := router.route(host)
+	return pool
+}
+
+// RouteName returns the name of the pool for the host, the via of its rule: direct, the name of a
+// pool or a proxy url. Hosts without a rule use the pool named default.
+func (router *Router) RouteName(host string) string {
+	name, _ := router.route(host)
+	return name
+}
+
+func (router *Router) route(host string) (string, *Pool) {
+	if router == nil {
+		return "direct", nil
+	}
+	host = strings.ToLower(host)
+	for _, rule := range router.rules {
+		if !matches(host, strings.ToLower(rule.Match)) {
+			continue
+		}
+		if rule.Via == "direct" {
+			return "direct", nil
+		}
+		return rule.Via, router.pools[rule.Via]
+	}
+	if router.fallback.Len() == 0 {
+		return "direct", nil
+	}
+	return "default", router.fallback
+}
+
+// Pools returns every pool that has proxies, keyed by name. The default pool is named default.
+func (router *Router) Pools() map[string]*Pool {
+	pools := map[string]*Pool{}
+	if router.fallback.Len() > 0 {
+		pools["default"] = router.fallback
+	}
+	for name, pool := range router.pools {
+		if pool.Len() > 0 {
+			pools[name] = pool
+		}
+	}
+	return pools
+}
+
+func matches(host, match string) bool {
+	if match == "*" {
+		return true
+	}
+	match = strings.TrimPrefix(match, ".")
+	return host == match || strings.HasSuffix(host, "."+match)
+}
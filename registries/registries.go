@@ -0,0 +1,158 @@
+package registries
+
+import (
+	"fmt"
+	"net/url"
+	"regexp"
+	"sort"
+	"strings"
+
+	"github.com/tidwall/gjson"
+)
+
+// Fetch returns the body of a url, so the lookups go through the same proxies as the checks.
+type Fetch func(target string) ([]byte, error)
+
+// Author is what the packages of an account tell about their author.
+type Author struct {
+	Emails []string
+	Links  []string
+}
+
+// Most packages whose metadata is fetched one by one.
+const maxPackages = 20
+
+var (
+	pypiPackagePattern = regexp.MustCompile(`href="/project/([^/"]+)/"`)
+	emailPattern       = regexp.MustCompile(`[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}`)
+)
+
+var lookups = map[string]func(username string, fetch Fetch) (Author, error){
+	"npm":       npm,
+	"pypi":      pypi,
+	"crates.io": crates,
+	"rubygems":  rubygems,
+}
+
+// Supports tells whether the packages of the accounts of the site can be looked up.
+func Supports(site string) bool {
+	_, ok := lookups[strings.ToLower(site)]
+	return ok
+}
+
+// Lookup returns the emails and the homepage and repository links listed in the metadata of the
+// packages the username publishes on the registry site.
+func Lookup(site, username string, fetch Fetch) (Author, error) {
+	lookup, ok := lookups[strings.ToLower(site)]
+	if !ok {
+		return Author{}, fmt.Errorf("no package lookup for %s", site)
+	}
+	author, err := lookup(username, fetch)
+	var emails, links []string
+	for _, email := range author.Emails {
+		emails = append(emails, strings.ToLower(email))
+	}
+	for _, link := range author.Links {
+		// Also drops placeholders such as the UNKNOWN home_page of old PyPI packages.
+		if strings.HasPrefix(link, "https://") || strings.HasPrefix(link, "http://") {
+			links = append(links, link)
+		}
+	}
+	return Author{Emails: unique(emails), Links: unique(links)}, err
+}
+
+func npm(username string, fetch Fetch) (Author, error) {
+	body, err := fetch("https://registry.npmjs.org/-/v1/search?size=250&text=maintainer:" + url.QueryEscape(username))
+	if err != nil {
+		return Author{}, err
+	}
+	var author Author
+	for _, object := range gjson.GetBytes(body, "objects").Array() {
+		pkg := object.Get("package")
+		// Only the email of the account itself, not of the other maintainers of the package.
+		for _, maintainer := range pkg.Get("maintainers").Array() {
+			if strings.EqualFold(maintainer.Get("username").String(), username) {
+				author.Emails = append(author.Emails, maintainer.Get("email").String())
+			}
+		}
+		author.Links = append(author.Links, pkg.Get("links.homepage").String(), pkg.Get("links.repository").String())
+	}
+	return author, nil
+}
+
+func pypi(username string, fetch Fetch) (Author, error) {
+	page, err := fetch("https://pypi.org/user/" + url.PathEscape(username) + "/")
+	if err != nil {
+		return Author{}, err
+	}
+	var author Author
+	for i, match := range pypiPackagePattern.FindAllSubmatch(page, -1) {
+		if i == maxPackages {
+			break
+		}
+		body, err := fetch("https://pypi.org/pypi/" + string(match[1]) + "/json")
+		if err != nil {
+			continue
+		}
+		info := gjson.GetBytes(body, "info")
+		for _, field := range []string{"author_email", "maintainer_email"} {
+			author.Emails = append(author.Emails, emailPattern.FindAllString(info.Get(field).String(), -1)...)
+		}
+		author.Links = append(author.Links, info.Get("home_page").String())
+		info.Get("project_urls").ForEach(func(_, link gjson.Result) bool {
+			author.Links = append(author.Links, link.String())
+			return true
+		})
+	}
+	return author, nil
+}
+
+func crates(username string, fetch Fetch) (Author, error) {
+	body, err := fetch("https://crates.io/api/v1/users/" + url.PathEscape(username))
+	if err != nil {
+		return Author{}, err
+	}
+	user := gjson.GetBytes(body, "user")
+	author := Author{Links: []string{user.Get("url").String()}}
+
+	body, err = fetch("https://crates.io/api/v1/crates?per_page=100&user_id=" + user.Get("id").String())
+	if err != nil {
+		return author, err
+	}
+	for _, crate := range gjson.GetBytes(body, "crates").Array() {
+		author.Links = append(author.Links, crate.Get("homepage").String(), crate.Get("repository").String())
+	}
+	return author, nil
+}
+
+func rubygems(username string, fetch Fetch) (Author, error) {
+	body, err := fetch("https://rubygems.org/api/v1/owners/" + url.PathEscape(username) + "/gems.json")
+	if err != nil {
+		return Author{}, err
+	}
+	var author Author
+	for _, gem := range gjson.ParseBytes(body).Array() {
+		author.Links = append(author.Links,
+			gem.Get("homepage_uri").String(),
+			gem.Get("source_code_uri").String(),
+			gem.Get("metadata.homepage_uri").String(),
+		)
+	}
+	return author, nil
+}
+
+// unique drops the empty and duplicate values and sorts the others.
+func unique(values []string) []string {
+	seen := map[string]bool{}
+	var kept []string
+	for _, value := range values {
+		value = strings.TrimSpace(value)
+		if value == "" || seen[value] {
+			continue
+		}
+		seen[value] = true
+		kept = append(kept, value)
+	}
+	sort.Strings(kept)
+	return kept
+}
@@ -0,0 +1,121 @@
+package schedule
+
+import (
+	"strconv"
+	"strings"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+// Cron is a parsed five field cron expression: minute, hour, day of month, month and day of week.
+type Cron struct {
+	minute     uint64
+	hour       uint64
+	dayOfMonth uint64
+	month      uint64
+	dayOfWeek  uint64
+	// As in cron(8), when both day fields are restricted either of them may match.
+	dayAny bool
+}
+
+var fieldBounds = [5][2]int{
+	{0, 59},
+	{0, 23},
+	{1, 31},
+	{1, 12},
+	{0, 7},
+}
+
+// Parse parses a cron expression of five fields separated by spaces. Each field is a list of
+// values, ranges such as 1-5 and steps such as */15 or 0-30/10, and 0 or 7 is sunday in the day of
+// week. Names of months and days and shorthands such as @daily are not supported.
+func Parse(expression string) (*Cron, error) {
+	fields := strings.Fields(expression)
+	if len(fields) != 5 {
+		return nil, errors.Errorf("expected 5 fields, got %d", len(fields))
+	}
+
+	var sets [5]uint64
+	for i, field := range fields {
+		set, err := parseField(field, fieldBounds[i][0], fieldBounds[i][1])
+		if err != nil {
+			return nil, err
+		}
+		sets[i] = set
+	}
+
+	// Both 0 and 7 are sunday.
+	if sets[4]&(1<<7) != 0 {
+		sets[4] |= 1
+	}
+
+	return &Cron{
+		minute:     sets[0],
+		hour:       sets[1],
+		dayOfMonth: sets[2],
+		month:      sets[3],
+		dayOfWeek:  sets[4],
+		dayAny:     strings.HasPrefix(fields[2], "*") || strings.HasPrefix(fields[4], "*"),
+	}, nil
+}
+
+func parseField(field string, min, max int) (uint64, error) {
+	var set uint64
+
+	for _, part := range strings.Split(field, ",") {
+		expression := part
+		step := 1
+		if i := strings.Index(part, "/"); i >= 0 {
+			var err error
+			if step, err = strconv.Atoi(part[i+1:]); err != nil || step <= 0 {
+				return 0, errors.Errorf("invalid step in \"%s\"", expression)
+			}
+			part = part[:i]
+		}
+
+		low, high := min, max
+		if part != "*" {
+			bounds := strings.SplitN(part, "-", 2)
+			var err error
+			if low, err = strconv.Atoi(bounds[0]); err != nil {
+				return 0, errors.Errorf("invalid value in \"%s\"", expression)
+			}
+			high = low
+			if len(bounds) == 2 {
+				if high, err = strconv.Atoi(bounds[1]); err != nil {
+					return 0, errors.Errorf("invalid value in \"%s\"", expression)
+				}
+			} else if step != 1 {
+				high = max
+			}
+		}
+
+		if low < min || high > max || low > high {
+			return 0, errors.Errorf("\"%s\" is out of range %d-%d", expression, min, max)
+		}
+		for value := low; value <= high; value += step {
+			set |= 1 << uint(value)
+		}
+	}
+
+	return set, nil
+}
+
+func has(set uint64, value int) bool {
+	return set&(1<<uint(value)) != 0
+}
+
+// Match reports whether the expression fires at the minute of t.
+func (cron *Cron) Match(t time.Time) bool {
+	if !has(cron.minute, t.Minute()) || !has(cron.hour, t.Hour()) || !has(cron.month, int(t.Month())) {
+		return false
+	}
+
+	dayOfMonth := has(cron.dayOfMonth, t.Day())
+	dayOfWeek := has(cron.dayOfWeek, int(t.Weekday()))
+	if cron.dayAny {
+		return dayOfMonth && dayOfWeek
+	}
+	return dayOfMonth || dayOfWeek
+}
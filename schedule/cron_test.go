@@ -0,0 +1,73 @@
+package schedule
+
+import (
+	"testing"
+	"time"
+)
+
+func TestParse(t *testing.T) {
+	tests := []struct {
+		expression string
+		valid      bool
+	}{
+		{"* * * * *", true},
+		{"*/15 * * * *", true},
+		{"0 9-17 * * 1-5", true},
+		{"0,30 6 1 1,7 *", true},
+		{"5/10 * * * *", true},
+		{"0 0 * * 7", true},
+		{"* * * *", false},
+		{"* * * * * *", false},
+		{"60 * * * *", false},
+		{"* 24 * * *", false},
+		{"* * 0 * *", false},
+		{"* * * 13 *", false},
+		{"* * * * 8", false},
+		{"5-1 * * * *", false},
+		{"*/0 * * * *", false},
+		{"a * * * *", false},
+		{"1-a * * * *", false},
+	}
+	for _, test := range tests {
+		if _, err := Parse(test.expression); (err == nil) != test.valid {
+			t.Errorf("Parse(%q) error = %v, want valid %t", test.expression, err, test.valid)
+		}
+	}
+}
+
+func TestMatch(t *testing.T) {
+	// Monday the 1st of March 2021.
+	monday := time.Date(2021, time.March, 1, 9, 30, 0, 0, time.UTC)
+	tests := []struct {
+		expression string
+		time       time.Time
+		want       bool
+	}{
+		{"* * * * *", monday, true},
+		{"30 9 * * *", monday, true},
+		{"31 9 * * *", monday, false},
+		{"*/15 * * * *", monday, true},
+		{"*/20 * * * *", monday, false},
+		{"0-30 9-17 * * 1-5", monday, true},
+		{"30 9 * * 0", monday, false},
+		{"30 9 * * 7", monday.AddDate(0, 0, 6), true},
+		{"30 9 * 2 *", monday, false},
+		// With both day fields restricted, either of them matches.
+		{"30 9 1 * 5", monday, true},
+		{"30 9 2 * 1", monday, true},
+		{"30 9 2 * 5", monday, false},
+		// With one of them unrestricted, the other one has to match.
+		{"30 9 2 * *", monday, false},
+		{"30 9 * * 5", monday, false},
+	}
+	for _, test := range tests {
+		cron, err := Parse(test.expression)
+		if err != nil {
+			t.Errorf("Parse(%q) error = %s", test.expression, err)
+			continue
+		}
+		if got := cron.Match(test.time); got != test.want {
+			t.Errorf("Parse(%q).Match(%s) = %t, want %t", test.expression, test.time, got, test.want)
+		}
+	}
+}
@@ -0,0 +1,188 @@
+package schema
+
+// Version of the report format. Fields are only ever added, a change that breaks existing
+// consumers gets a new version.
+const Version = 2
+
+// Report is the JSON Schema of the json report, a list of the reports of every username.
+const Report = `{
+  "$schema": "http://json-schema.org/draft-07/schema#",
+  "$id": "https://github.com/krishpranav/maigret/schema/report-2.json",
+  "title": "maigret report",
+  "type": "array",
+  "items": {
+    "type": "object",
+    "required": ["schema_version", "username", "date", "checked", "found", "results"],
+    "properties": {
+      "schema_version": {"const": 2},
+      "username": {"type": "string"},
+      "date": {"type": "string", "format": "date-time"},
+      "checked": {"type": "integer", "minimum": 0},
+      "found": {"type": "integer", "minimum": 0},
+      "skipped": {"type": "integer", "minimum": 0},
+      "changed": {"type": "integer", "minimum": 0},
+      "incomplete": {"type": "boolean"},
+      "metadata": {"type": "object", "additionalProperties": {"type": "string"}},
+      "results": {"type": "array", "items": {"$ref": "#/definitions/result"}},
+      "errors": {
+        "type": "array",
+        "items": {
+          "type": "object",
+          "properties": {
+            "site": {"type": "string"},
+            "category": {"$ref": "#/definitions/error_category"},
+            "message": {"type": "string"}
+          }
+        }
+      },
+      "error_counts": {"type": "object", "additionalProperties": {"type": "integer"}},
+      "domains": {
+        "type": "array",
+        "items": {
+          "type": "object",
+          "required": ["domain", "requests", "errors", "error_rate", "p50_ms", "p95_ms"],
+          "properties": {
+            "domain": {"type": "string"},
+            "requests": {"type": "integer"},
+            "errors": {"type": "integer"},
+            "error_rate": {"type": "number"},
+            "p50_ms": {"type": "integer"},
+            "p95_ms": {"type": "integer"}
+          }
+        }
+      },
+      "avatar_clusters": {"type": "array", "items": {"type": "array", "items": {"type": "string"}}},
+      "pgp_keys": {
+        "type": "array",
+        "items": {
+          "type": "object",
+          "required": ["key_id", "created", "uids", "servers", "queries"],
+          "properties": {
+            "key_id": {"type": "string"},
+            "created": {"type": "string", "format": "date-time"},
+            "expires": {"type": "string", "format": "date-time"},
+            "uids": {"type": "array", "items": {"type": "string"}},
+            "emails": {"type": "array", "items": {"type": "string"}},
+            "servers": {"type": "array", "items": {"type": "string"}},
+            "queries": {"type": "array", "items": {"type": "string"}}
+          }
+        }
+      },
+      "hostnames": {
+        "type": "array",
+        "items": {
+          "type": "object",
+          "required": ["name", "certificates", "first_seen", "last_seen"],
+          "properties": {
+            "name": {"type": "string"},
+            "certificates": {"type": "integer"},
+            "first_seen": {"type": "string", "format": "date-time"},
+            "last_seen": {"type": "string", "format": "date-time"}
+          }
+        }
+      },
+      "leak_mentions": {
+        "type": "array",
+        "items": {
+          "type": "object",
+          "required": ["term", "name", "date", "bucket", "media", "url"],
+          "properties": {
+            "term": {"type": "string"},
+            "name": {"type": "string"},
+            "date": {"type": "string", "format": "date-time"},
+            "bucket": {"type": "string"},
+            "media": {"type": "string"},
+            "url": {"type": "string"}
+          }
+        }
+      },
+      "email_reputation": {
+        "type": "object",
+        "required": ["email", "reputation", "suspicious", "references", "data_breach", "credentials_leaked"],
+        "properties": {
+          "email": {"type": "string"},
+          "reputation": {"type": "string"},
+          "suspicious": {"type": "boolean"},
+          "references": {"type": "integer"},
+          "first_seen": {"type": "string"},
+          "last_seen": {"type": "string"},
+          "data_breach": {"type": "boolean"},
+          "credentials_leaked": {"type": "boolean"},
+          "profiles": {"type": "array", "items": {"type": "string"}}
+        }
+      },
+      "media": {
+        "type": "array",
+        "items": {
+          "type": "object",
+          "required": ["file"],
+          "properties": {
+            "file": {"type": "string"},
+            "make": {"type": "string"},
+            "model": {"type": "string"},
+            "software": {"type": "string"},
+            "artist": {"type": "string"},
+            "copyright": {"type": "string"},
+            "date_taken": {"type": "string"},
+            "owner_name": {"type": "string"},
+            "serial_number": {"type": "string"},
+            "lens_serial_number": {"type": "string"},
+            "latitude": {"type": "number"},
+            "longitude": {"type": "number"},
+            "xmp": {"type": "object", "additionalProperties": {"type": "string"}}
+          }
+        }
+      }
+    }
+  },
+  "definitions": {
+    "error_category": {"enum": ["dns", "timeout", "tls", "connection", "blocked", "parse", "invalid"]},
+    "result": {
+      "type": "object",
+      "required": ["username", "exists", "site", "error", "status"],
+      "properties": {
+        "username": {"type": "string"},
+        "exists": {"type": "boolean"},
+        "proxied": {"type": "boolean"},
+        "site": {"type": "string"},
+        "url": {"type": "string", "description": "url template of the site"},
+        "url_probe": {"type": "string"},
+        "link": {"type": "string", "description": "profile url of a found account"},
+        "error": {"type": "boolean"},
+        "error_msg": {"type": "string"},
+        "status": {"enum": ["found", "not_found", "error", "skipped", "blocked", "captcha", "private", "invalid"]},
+        "blocked_by": {"type": "string"},
+        "retries": {"type": "integer", "minimum": 0},
+        "duration_ns": {"type": "integer", "minimum": 0},
+        "confidence": {"type": "number", "minimum": 0, "maximum": 1},
+        "insecure_tls": {"type": "boolean"},
+        "profile": {
+          "type": "object",
+          "properties": {
+            "name": {"type": "string"},
+            "avatar": {"type": "string"},
+            "links": {"type": "array", "items": {"type": "string"}},
+            "emails": {"type": "array", "items": {"type": "string"}}
+          }
+        },
+        "content_hash": {"type": "string"},
+        "avatar_phash": {"type": "string"},
+        "content_changed": {"type": "boolean"},
+        "unchanged": {"type": "boolean"},
+        "error_category": {"$ref": "#/definitions/error_category"},
+        "lead": {"type": "string", "description": "service that reported an account of the email on the site"},
+        "http": {
+          "type": "object",
+          "properties": {
+            "status_code": {"type": "integer"},
+            "remote_addr": {"type": "string"},
+            "headers": {"type": "object", "additionalProperties": {"type": "string"}},
+            "redirects": {"type": "array", "items": {"type": "string"}},
+            "tls_issuer": {"type": "string"}
+          }
+        }
+      }
+    }
+  }
+}
+`
@@ -0,0 +1,61 @@
+package scoring
+
+// Weights of the signals that make up the confidence of a found account. Only the signals that
+// apply to a site count, so the confidence is always on a 0 to 1 scale: 1 means every applicable
+// signal agrees that the profile exists.
+type Weights struct {
+	StatusCode  float64 `json:"status_code"`
+	ErrorAbsent float64 `json:"error_absent"`
+	Presence    float64 `json:"presence"`
+	NoRedirect  float64 `json:"no_redirect"`
+	Dissimilar  float64 `json:"dissimilar"`
+}
+
+var DefaultWeights = Weights{
+	StatusCode:  0.3,
+	ErrorAbsent: 0.3,
+	Presence:    0.2,
+	NoRedirect:  0.1,
+	Dissimilar:  0.3,
+}
+
+type Signals struct {
+	// The probe answered with 200 OK.
+	StatusOK bool
+	// The site has an error message and it is not on the page.
+	ErrorChecked bool
+	ErrorAbsent  bool
+	// The site has presence strings and one of them is on the page.
+	PresenceChecked bool
+	PresenceFound   bool
+	// The probe was redirected to another url.
+	Redirected bool
+	// The page was compared with the unclaimed username page and differs from it.
+	SimilarityChecked bool
+	Dissimilar        bool
+}
+
+func Score(signals Signals, weights Weights) float64 {
+	var total, score float64
+
+	add := func(applies, passed bool, weight float64) {
+		if !applies {
+			return
+		}
+		total += weight
+		if passed {
+			score += weight
+		}
+	}
+
+	add(true, signals.StatusOK, weights.StatusCode)
+	add(signals.ErrorChecked, signals.ErrorAbsent, weights.ErrorAbsent)
+	add(signals.PresenceChecked, signals.PresenceFound, weights.Presence)
+	add(true, !signals.Redirected, weights.NoRedirect)
+	add(signals.SimilarityChecked, signals.Dissimilar, weights.Dissimilar)
+
+	if total == 0 {
+		return 0
+	}
+	return score / total
+}
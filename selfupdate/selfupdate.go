@@ -0,0 +1,199 @@
+package selfupdate
+
+import (
+	"bufio"
+	"bytes"
+	"crypto/ed25519"
+	"crypto/sha256"
+	"encoding/hex"
+	"io/ioutil"
+	"net/http"
+	"os"
+	"path/filepath"
+	"runtime"
+	"strings"
+	"time"
+
+	gover "github.com/mcuadros/go-version"
+	"github.com/pkg/errors"
+	"github.com/tidwall/gjson"
+)
+
+const releasesURL = "https://api.github.com/repos/krishpranav/maigret/releases/latest"
+
+// Release binaries are named maigret_<os>_<arch>, with .exe on windows, and listed with their
+// sha256 in checksums.txt as written by sha256sum. checksums.txt.sig is its raw ed25519 signature,
+// as written by openssl pkeyutl -sign -rawin.
+const (
+	checksumsAsset = "checksums.txt"
+	signatureAsset = "checksums.txt.sig"
+)
+
+// PublicKey is the hex ed25519 key signing the releases, pinned by release builds with
+// -ldflags "-X github.com/krishpranav/maigret/selfupdate.PublicKey=...". Builds without it can't
+// update themselves.
+var PublicKey string
+
+var client = &http.Client{Timeout: 5 * time.Minute}
+
+// Release is the latest release and the download urls of its assets.
+type Release struct {
+	Version string
+	Assets  map[string]string
+}
+
+// Latest asks GitHub for the latest release.
+func Latest() (Release, error) {
+	body, err := get(releasesURL)
+	if err != nil {
+		return Release{}, err
+	}
+
+	release := Release{
+		Version: strings.TrimPrefix(gjson.GetBytes(body, "tag_name").String(), "v"),
+		Assets:  map[string]string{},
+	}
+	for _, asset := range gjson.GetBytes(body, "assets").Array() {
+		release.Assets[asset.Get("name").String()] = asset.Get("browser_download_url").String()
+	}
+	if release.Version == "" {
+		return Release{}, errors.New("no release found")
+	}
+	return release, nil
+}
+
+// Newer tells whether the release is newer than the current version. Development builds are not
+// releases and are never replaced by one.
+func (release Release) Newer(current string) bool {
+	if current == "dev" {
+		return false
+	}
+	return gover.Compare(release.Version, strings.TrimPrefix(current, "v"), ">")
+}
+
+// AssetName returns the name of the binary for this platform.
+func AssetName() string {
+	name := "maigret_" + runtime.GOOS + "_" + runtime.GOARCH
+	if runtime.GOOS == "windows" {
+		name += ".exe"
+	}
+	return name
+}
+
+// Apply downloads the binary of the release, checks it against checksums.txt, whose signature is
+// verified with PublicKey, and replaces the running executable with it.
+func (release Release) Apply() error {
+	key, err := hex.DecodeString(PublicKey)
+	if err != nil || len(key) != ed25519.PublicKeySize {
+		return errors.New("this build has no release key to verify the update with, update it by hand")
+	}
+	name := AssetName()
+	binaryURL, ok := release.Assets[name]
+	if !ok {
+		return errors.Errorf("release %s has no binary for %s/%s", release.Version, runtime.GOOS, runtime.GOARCH)
+	}
+	checksumsURL, ok := release.Assets[checksumsAsset]
+	if !ok {
+		return errors.Errorf("release %s has no %s, refusing to install an unverified binary", release.Version, checksumsAsset)
+	}
+
+	signatureURL, ok := release.Assets[signatureAsset]
+	if !ok {
+		return errors.Errorf("release %s has no %s, refusing to install an unverified binary", release.Version, signatureAsset)
+	}
+
+	checksums, err := get(checksumsURL)
+	if err != nil {
+		return err
+	}
+	signature, err := get(signatureURL)
+	if err != nil {
+		return err
+	}
+	if !ed25519.Verify(ed25519.PublicKey(key), checksums, signature) {
+		return errors.Errorf("the signature of %s of release %s is invalid", checksumsAsset, release.Version)
+	}
+	expected, err := findChecksum(checksums, name)
+	if err != nil {
+		return err
+	}
+
+	binary, err := get(binaryURL)
+	if err != nil {
+		return err
+	}
+	sum := sha256.Sum256(binary)
+	if hex.EncodeToString(sum[:]) != expected {
+		return errors.Errorf("checksum mismatch of %s", name)
+	}
+
+	return replaceExecutable(binary)
+}
+
+func findChecksum(checksums []byte, name string) (string, error) {
+	scanner := bufio.NewScanner(bytes.NewReader(checksums))
+	for scanner.Scan() {
+		fields := strings.Fields(scanner.Text())
+		if len(fields) == 2 && strings.TrimPrefix(fields[1], "*") == name {
+			return strings.ToLower(fields[0]), nil
+		}
+	}
+	return "", errors.Errorf("%s is not listed in %s", name, checksumsAsset)
+}
+
+// replaceExecutable writes the new binary next to the running one and renames it over it, so a
+// failed download never leaves a broken executable behind.
+func replaceExecutable(binary []byte) error {
+	executable, err := os.Executable()
+	if err != nil {
+		return err
+	}
+	if executable, err = filepath.EvalSymlinks(executable); err != nil {
+		return err
+	}
+
+	info, err := os.Stat(executable)
+	if err != nil {
+		return err
+	}
+
+	dir := filepath.Dir(executable)
+	temp, err := ioutil.TempFile(dir, ".maigret-update-")
+	if err != nil {
+		return errors.Wrap(err, "cannot write next to the executable")
+	}
+	defer os.Remove(temp.Name())
+
+	if _, err := temp.Write(binary); err != nil {
+		temp.Close()
+		return err
+	}
+	if err := temp.Close(); err != nil {
+		return err
+	}
+	if err := os.Chmod(temp.Name(), info.Mode()); err != nil {
+		return err
+	}
+
+	// Windows can't replace a running executable, but it can rename it.
+	if runtime.GOOS == "windows" {
+		old := executable + ".old"
+		os.Remove(old)
+		if err := os.Rename(executable, old); err != nil {
+			return err
+		}
+	}
+	return os.Rename(temp.Name(), executable)
+}
+
+func get(url string) ([]byte, error) {
+	response, err := client.Get(url)
+	if err != nil {
+		return nil, err
+	}
+	defer response.Body.Close()
+	if response.StatusCode != http.StatusOK {
+		return nil, errors.Errorf("%s answered %s", url, response.Status)
+	}
+	return ioutil.ReadAll(response.Body)
+}
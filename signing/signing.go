@@ -0,0 +1,282 @@
+package signing
+
+import (
+	"bytes"
+	"context"
+	"crypto/ed25519"
+	"crypto/rand"
+	"crypto/sha256"
+	"crypto/x509"
+	"crypto/x509/pkix"
+	"encoding/asn1"
+	"encoding/hex"
+	"encoding/json"
+	"encoding/pem"
+	"errors"
+	"fmt"
+	"io/ioutil"
+	"math/big"
+	"net/http"
+	"path/filepath"
+	"time"
+)
+
+// Config is the key signing the reports and the timestamp authority vouching for their time.
+type Config struct {
+	// PEM file of an ed25519 private key, such as one made by openssl genpkey -algorithm ed25519.
+	Key string `json:"key"`
+	// RFC 3161 timestamp authority, e.g. https://freetsa.org/tsr. No timestamp is taken without it.
+	TimestampURL string `json:"timestamp_url"`
+}
+
+// Signature is the sidecar of a signed file. The signature is over the bytes of the file, the
+// timestamp token over their SHA-256, so either can be verified on its own, the token with
+// openssl ts -verify -data FILE -token_in.
+type Signature struct {
+	File      string    `json:"file"`
+	SHA256    string    `json:"sha256"`
+	Algorithm string    `json:"algorithm"`
+	PublicKey []byte    `json:"public_key"`
+	Signature []byte    `json:"signature"`
+	SignedAt  time.Time `json:"signed_at"`
+	// DER of the RFC 3161 TimeStampToken, with the certificate of the authority.
+	TimestampToken []byte `json:"timestamp_token,omitempty"`
+	TimestampURL   string `json:"timestamp_url,omitempty"`
+}
+
+// Send makes a request through the proxies of the scan and returns its response.
+type Send func(ctx context.Context, method, target, body string, headers http.Header) (*http.Response, error)
+
+// Signer signs report files with the key of the config.
+type Signer struct {
+	key          ed25519.PrivateKey
+	timestampURL string
+	send         Send
+}
+
+// New loads the key of the config. The timestamps are requested with send.
+func New(config Config, send Send) (*Signer, error) {
+	if config.Key == "" {
+		return nil, errors.New("signing needs the key of an ed25519 private key file")
+	}
+	key, err := LoadKey(config.Key)
+	if err != nil {
+		return nil, err
+	}
+	return &Signer{key: key, timestampURL: config.TimestampURL, send: send}, nil
+}
+
+// SignFile signs the file at path as written and saves its signature to path.sig.json, whose
+// path it returns. The signature is saved without its timestamp when the authority fails.
+func (signer *Signer) SignFile(path string) (string, error) {
+	data, err := ioutil.ReadFile(path)
+	if err != nil {
+		return "", err
+	}
+	signature, signErr := Sign(signer.key, signer.timestampURL, filepath.Base(path), data, signer.send)
+	encoded, err := json.MarshalIndent(signature, "", "  ")
+	if err != nil {
+		return "", err
+	}
+	sidecar := path + ".sig.json"
+	if err := ioutil.WriteFile(sidecar, encoded, 0644); err != nil {
+		return "", err
+	}
+	if signErr != nil {
+		return sidecar, fmt.Errorf("no timestamp for %s: %s", path, signErr)
+	}
+	return sidecar, nil
+}
+
+// LoadKey reads the PKCS #8 ed25519 private key of a PEM file.
+func LoadKey(path string) (ed25519.PrivateKey, error) {
+	data, err := ioutil.ReadFile(path)
+	if err != nil {
+		return nil, err
+	}
+	block, _ := pem.Decode(data)
+	if block == nil {
+		return nil, fmt.Errorf("%s is not a PEM file", path)
+	}
+	parsed, err := x509.ParsePKCS8PrivateKey(block.Bytes)
+	if err != nil {
+		return nil, err
+	}
+	key, ok := parsed.(ed25519.PrivateKey)
+	if !ok {
+		return nil, fmt.Errorf("%s is not an ed25519 key", path)
+	}
+	return key, nil
+}
+
+// Sign signs the data of the file named name and, with a timestamp authority, has the time of
+// its hash certified.
+func Sign(key ed25519.PrivateKey, timestampURL, name string, data []byte, send Send) (Signature, error) {
+	digest := sha256.Sum256(data)
+	signature := Signature{
+		File:      name,
+		SHA256:    hex.EncodeToString(digest[:]),
+		Algorithm: "ed25519",
+		PublicKey: key.Public().(ed25519.PublicKey),
+		Signature: ed25519.Sign(key, data),
+		SignedAt:  time.Now().UTC(),
+	}
+	if timestampURL != "" {
+		token, err := Timestamp(timestampURL, digest[:], send)
+		if err != nil {
+			return signature, err
+		}
+		signature.TimestampToken = token
+		signature.TimestampURL = timestampURL
+	}
+	return signature, nil
+}
+
+type messageImprint struct {
+	HashAlgorithm pkix.AlgorithmIdentifier
+	HashedMessage []byte
+}
+
+type timeStampReq struct {
+	Version        int
+	MessageImprint messageImprint
+	Nonce          *big.Int `asn1:"optional"`
+	CertReq        bool     `asn1:"optional,default:false"`
+}
+
+type pkiStatusInfo struct {
+	Status       int
+	StatusString []string      `asn1:"optional,utf8"`
+	FailInfo     asn1.BitString `asn1:"optional"`
+}
+
+type timeStampResp struct {
+	Status         pkiStatusInfo
+	TimeStampToken asn1.RawValue `asn1:"optional"`
+}
+
+// The token is a CMS ContentInfo whose SignedData encapsulates the TSTInfo. The fields after the
+// ones read here are left out.
+type contentInfo struct {
+	ContentType asn1.ObjectIdentifier
+	Content     asn1.RawValue `asn1:"explicit,tag:0"`
+}
+
+type signedData struct {
+	Version          int
+	DigestAlgorithms asn1.RawValue
+	EncapContentInfo encapsulatedContentInfo
+}
+
+type encapsulatedContentInfo struct {
+	EContentType asn1.ObjectIdentifier
+	EContent     []byte `asn1:"explicit,optional,tag:0"`
+}
+
+type accuracy struct {
+	Seconds int `asn1:"optional"`
+	Millis  int `asn1:"optional,tag:0"`
+	Micros  int `asn1:"optional,tag:1"`
+}
+
+type tstInfo struct {
+	Version        int
+	Policy         asn1.ObjectIdentifier
+	MessageImprint messageImprint
+	SerialNumber   *big.Int
+	GenTime        time.Time `asn1:"generalized"`
+	Accuracy       accuracy  `asn1:"optional"`
+	Ordering       bool      `asn1:"optional,default:false"`
+	Nonce          *big.Int  `asn1:"optional"`
+}
+
+var (
+	oidSHA256     = asn1.ObjectIdentifier{2, 16, 840, 1, 101, 3, 4, 2, 1}
+	oidSignedData = asn1.ObjectIdentifier{1, 2, 840, 113549, 1, 7, 2}
+	oidTSTInfo    = asn1.ObjectIdentifier{1, 2, 840, 113549, 1, 9, 16, 1, 4}
+)
+
+// Timestamp asks the authority to certify the time of a SHA-256 digest and returns the token.
+// The nonce and the digest of the token are checked against the request, its CMS signature and
+// certificate are left to the verifier.
+func Timestamp(authority string, digest []byte, send Send) ([]byte, error) {
+	nonce, err := rand.Int(rand.Reader, new(big.Int).Lsh(big.NewInt(1), 64))
+	if err != nil {
+		return nil, err
+	}
+	request, err := asn1.Marshal(timeStampReq{
+		Version: 1,
+		MessageImprint: messageImprint{
+			HashAlgorithm: pkix.AlgorithmIdentifier{Algorithm: oidSHA256, Parameters: asn1.NullRawValue},
+			HashedMessage: digest,
+		},
+		Nonce:   nonce,
+		CertReq: true,
+	})
+	if err != nil {
+		return nil, err
+	}
+
+	ctx, cancel := context.WithTimeout(context.Background(), 30*time.Second)
+	defer cancel()
+	headers := http.Header{"Content-Type": {"application/timestamp-query"}}
+	response, err := send(ctx, http.MethodPost, authority, string(request), headers)
+	if err != nil {
+		return nil, err
+	}
+	defer response.Body.Close()
+	body, err := ioutil.ReadAll(response.Body)
+	if err != nil {
+		return nil, err
+	}
+	if response.StatusCode != http.StatusOK {
+		return nil, fmt.Errorf("the timestamp authority answered %s", response.Status)
+	}
+
+	var reply timeStampResp
+	if _, err := asn1.Unmarshal(body, &reply); err != nil {
+		return nil, fmt.Errorf("the timestamp authority answered an invalid response: %s", err)
+	}
+	// 0 is granted, 1 granted with modifications.
+	if reply.Status.Status > 1 {
+		return nil, fmt.Errorf("the timestamp authority refused the request (status %d) %v", reply.Status.Status, reply.Status.StatusString)
+	}
+	if len(reply.TimeStampToken.FullBytes) == 0 {
+		return nil, errors.New("the timestamp authority sent no token")
+	}
+	if err := checkToken(reply.TimeStampToken.FullBytes, digest, nonce); err != nil {
+		return nil, fmt.Errorf("the timestamp authority sent a token not matching the request: %s", err)
+	}
+	return reply.TimeStampToken.FullBytes, nil
+}
+
+// checkToken checks that the TSTInfo of the token has the nonce and the SHA-256 digest of the
+// request, so the token of another request, or a replayed one, is not taken for the time of the file.
+func checkToken(token, digest []byte, nonce *big.Int) error {
+	var content contentInfo
+	if _, err := asn1.Unmarshal(token, &content); err != nil {
+		return err
+	}
+	if !content.ContentType.Equal(oidSignedData) {
+		return fmt.Errorf("content type %s is not signed data", content.ContentType)
+	}
+	var signed signedData
+	if _, err := asn1.Unmarshal(content.Content.Bytes, &signed); err != nil {
+		return err
+	}
+	if !signed.EncapContentInfo.EContentType.Equal(oidTSTInfo) {
+		return fmt.Errorf("content type %s is not a TSTInfo", signed.EncapContentInfo.EContentType)
+	}
+	var info tstInfo
+	if _, err := asn1.Unmarshal(signed.EncapContentInfo.EContent, &info); err != nil {
+		return err
+	}
+	if info.Nonce == nil || info.Nonce.Cmp(nonce) != 0 {
+		return errors.New("the nonce differs")
+	}
+	if !info.MessageImprint.HashAlgorithm.Algorithm.Equal(oidSHA256) ||
+		!bytes.Equal(info.MessageImprint.HashedMessage, digest) {
+		return errors.New("the message imprint differs")
+	}
+	return nil
+}
@@ -0,0 +1,110 @@
+package telegram
+
+import (
+	"bytes"
+	"io/ioutil"
+	"mime/multipart"
+	"net/http"
+	"net/url"
+	"strconv"
+	"time"
+
+	"github.com/pkg/errors"
+	"github.com/tidwall/gjson"
+)
+
+const (
+	apiURL           string = "https://api.telegram.org/bot"
+	pollTimeout      int    = 50
+	maxMessageLength int    = 4096
+)
+
+var client = &http.Client{
+	Timeout: time.Duration(pollTimeout+10) * time.Second,
+}
+
+type Bot struct {
+	Token  string
+	offset int64
+}
+
+type Message struct {
+	ChatID int64
+	Text   string
+}
+
+func (bot *Bot) endpoint(method string) string {
+	return apiURL + bot.Token + "/" + method
+}
+
+func (bot *Bot) check(r *http.Response, err error) ([]byte, error) {
+	if err != nil {
+		return nil, err
+	}
+	defer r.Body.Close()
+
+	body, err := ioutil.ReadAll(r.Body)
+	if err != nil {
+		return nil, err
+	}
+	if !gjson.GetBytes(body, "ok").Bool() {
+		return nil, errors.New("telegram: " + gjson.GetBytes(body, "description").String())
+	}
+	return body, nil
+}
+
+// Updates long-polls the bot api and returns the text messages received since the last call.
+func (bot *Bot) Updates() ([]Message, error) {
+	body, err := bot.check(client.Get(
+		bot.endpoint("getUpdates") + "?timeout=" + strconv.Itoa(pollTimeout) + "&offset=" + strconv.FormatInt(bot.offset, 10),
+	))
+	if err != nil {
+		return nil, err
+	}
+
+	var messages []Message
+	for _, update := range gjson.GetBytes(body, "result").Array() {
+		bot.offset = update.Get("update_id").Int() + 1
+		text := update.Get("message.text")
+		if !text.Exists() {
+			continue
+		}
+		messages = append(messages, Message{
+			ChatID: update.Get("message.chat.id").Int(),
+			Text:   text.String(),
+		})
+	}
+	return messages, nil
+}
+
+func (bot *Bot) SendMessage(chatID int64, text string) error {
+	if len(text) > maxMessageLength {
+		text = text[:maxMessageLength-3] + "..."
+	}
+	_, err := bot.check(client.PostForm(bot.endpoint("sendMessage"), url.Values{
+		"chat_id": {strconv.FormatInt(chatID, 10)},
+		"text":    {text},
+	}))
+	return err
+}
+
+func (bot *Bot) SendDocument(chatID int64, filename string, data []byte) error {
+	var body bytes.Buffer
+	writer := multipart.NewWriter(&body)
+	if err := writer.WriteField("chat_id", strconv.FormatInt(chatID, 10)); err != nil {
+		return err
+	}
+	part, err := writer.CreateFormFile("document", filename)
+	if err != nil {
+		return err
+	}
+	if _, err := part.Write(data); err != nil {
+		return err
+	}
+	if err := writer.Close(); err != nil {
+		return err
+	}
+
+	_, err = bot.check(client.Post(bot.endpoint("sendDocument"), writer.FormDataContentType(), &body))
+	return err
+}
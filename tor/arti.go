@@ -0,0 +1,95 @@
+package tor
+
+import (
+	"net"
+	"os/exec"
+	"strconv"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+// Arti runs the arti Tor client as a SOCKS proxy, so Tor can be used without a system daemon.
+type Arti struct {
+	Path string
+	Port int
+	cmd  *exec.Cmd
+	done chan error
+}
+
+// Start launches arti and waits until its SOCKS port accepts connections.
+func (arti *Arti) Start(timeout time.Duration) error {
+	if arti.Path == "" {
+		path, err := exec.LookPath("arti")
+		if err != nil {
+			return errors.New("arti is not installed, see https://gitlab.torproject.org/tpo/core/arti")
+		}
+		arti.Path = path
+	}
+
+	if arti.Port == 0 {
+		port, err := freePort()
+		if err != nil {
+			return errors.Wrap(err, "no free port for the SOCKS proxy")
+		}
+		arti.Port = port
+	}
+
+	arti.cmd = exec.Command(arti.Path, "proxy", "-p", strconv.Itoa(arti.Port))
+	if err := arti.cmd.Start(); err != nil {
+		return errors.Wrap(err, "failed to run arti")
+	}
+
+	arti.done = make(chan error, 1)
+	go func() {
+		arti.done <- arti.cmd.Wait()
+		// Later receives don't block, so the client can be stopped more than once.
+		close(arti.done)
+	}()
+
+	deadline := time.Now().Add(timeout)
+	for time.Now().Before(deadline) {
+		select {
+		case err := <-arti.done:
+			return errors.Errorf("arti exited during bootstrap: %v", err)
+		default:
+		}
+
+		conn, err := net.DialTimeout("tcp", arti.host(), time.Second)
+		if err == nil {
+			conn.Close()
+			return nil
+		}
+		time.Sleep(500 * time.Millisecond)
+	}
+
+	arti.Stop()
+	return errors.Errorf("arti did not open its SOCKS port within %s", timeout)
+}
+
+// Address returns the SOCKS url of the running client.
+func (arti *Arti) Address() string {
+	return "socks5://" + arti.host()
+}
+
+// Stop kills the client, it does nothing on a nil or stopped Arti.
+func (arti *Arti) Stop() {
+	if arti == nil || arti.cmd == nil || arti.cmd.Process == nil {
+		return
+	}
+	arti.cmd.Process.Kill()
+	<-arti.done
+}
+
+func (arti *Arti) host() string {
+	return net.JoinHostPort("127.0.0.1", strconv.Itoa(arti.Port))
+}
+
+func freePort() (int, error) {
+	listener, err := net.Listen("tcp", "127.0.0.1:0")
+	if err != nil {
+		return 0, err
+	}
+	defer listener.Close()
+	return listener.Addr().(*net.TCPAddr).Port, nil
+}
@@ -0,0 +1,54 @@
+package tor
+
+import (
+	"io/ioutil"
+	"net"
+	"net/http"
+	"net/url"
+	"time"
+
+	"github.com/pkg/errors"
+	"github.com/tidwall/gjson"
+	"golang.org/x/net/proxy"
+)
+
+const checkURL = "https://check.torproject.org/api/ip"
+
+// Check makes sure the SOCKS proxy is reachable and that check.torproject.org sees a Tor exit,
+// it returns the exit address.
+func Check(proxyAddress string, timeout time.Duration) (string, error) {
+	proxyURL, err := url.Parse(proxyAddress)
+	if err != nil {
+		return "", errors.Wrap(err, "invalid proxy address")
+	}
+
+	conn, err := net.DialTimeout("tcp", proxyURL.Host, timeout)
+	if err != nil {
+		return "", errors.Errorf("nothing listens on %s, start Tor or use --arti", proxyURL.Host)
+	}
+	conn.Close()
+
+	dialer, err := proxy.FromURL(proxyURL, proxy.Direct)
+	if err != nil {
+		return "", err
+	}
+	client := &http.Client{
+		Timeout:   timeout,
+		Transport: &http.Transport{Dial: dialer.Dial},
+	}
+
+	response, err := client.Get(checkURL)
+	if err != nil {
+		return "", errors.Wrapf(err, "%s is not a working Tor SOCKS proxy", proxyURL.Host)
+	}
+	defer response.Body.Close()
+
+	body, err := ioutil.ReadAll(response.Body)
+	if err != nil {
+		return "", err
+	}
+	if !gjson.GetBytes(body, "IsTor").Bool() {
+		return "", errors.Errorf("%s is a proxy, but check.torproject.org does not see a Tor exit", proxyURL.Host)
+	}
+	return gjson.GetBytes(body, "IP").String(), nil
+}
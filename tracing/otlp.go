@@ -0,0 +1,188 @@
+package tracing
+
+import (
+	"bytes"
+	"crypto/rand"
+	"encoding/hex"
+	"encoding/json"
+	"net/http"
+	"strconv"
+	"sync"
+	"time"
+
+	"github.com/pkg/errors"
+)
+
+const (
+	spanKindInternal int = 1
+	spanKindClient   int = 3
+	statusOk         int = 1
+	statusError      int = 2
+)
+
+// Tracer collects finished spans and exports them to an OTLP/HTTP collector as json.
+// A nil *Tracer is valid and records nothing, so callers don't have to check whether tracing is on.
+type Tracer struct {
+	Service string
+	mutex   sync.Mutex
+	spans   []map[string]interface{}
+}
+
+type Span struct {
+	tracer     *Tracer
+	traceID    string
+	spanID     string
+	parentID   string
+	name       string
+	kind       int
+	start      time.Time
+	attributes map[string]interface{}
+	failed     bool
+}
+
+func randomID(size int) string {
+	id := make([]byte, size)
+	if _, err := rand.Read(id); err != nil {
+		panic(err)
+	}
+	return hex.EncodeToString(id)
+}
+
+// Start opens a span, as a child of parent or as the root of a new trace when parent is nil.
+func (tracer *Tracer) Start(name string, parent *Span) *Span {
+	if tracer == nil {
+		return nil
+	}
+
+	span := &Span{
+		tracer:     tracer,
+		spanID:     randomID(8),
+		name:       name,
+		kind:       spanKindInternal,
+		start:      time.Now(),
+		attributes: map[string]interface{}{},
+	}
+	if parent != nil {
+		span.traceID = parent.traceID
+		span.parentID = parent.spanID
+		span.kind = spanKindClient
+	} else {
+		span.traceID = randomID(16)
+	}
+	return span
+}
+
+func (span *Span) SetAttribute(key string, value interface{}) {
+	if span == nil {
+		return
+	}
+	span.attributes[key] = value
+}
+
+func (span *Span) SetError(message string) {
+	if span == nil {
+		return
+	}
+	span.failed = true
+	span.attributes["error.message"] = message
+}
+
+func attributeValue(value interface{}) map[string]interface{} {
+	switch v := value.(type) {
+	case bool:
+		return map[string]interface{}{"boolValue": v}
+	case int:
+		return map[string]interface{}{"intValue": strconv.Itoa(v)}
+	case int64:
+		return map[string]interface{}{"intValue": strconv.FormatInt(v, 10)}
+	case float64:
+		return map[string]interface{}{"doubleValue": v}
+	case string:
+		return map[string]interface{}{"stringValue": v}
+	default:
+		data, _ := json.Marshal(v)
+		return map[string]interface{}{"stringValue": string(data)}
+	}
+}
+
+func attributeList(attributes map[string]interface{}) []map[string]interface{} {
+	list := make([]map[string]interface{}, 0, len(attributes))
+	for key, value := range attributes {
+		list = append(list, map[string]interface{}{"key": key, "value": attributeValue(value)})
+	}
+	return list
+}
+
+func (span *Span) End() {
+	if span == nil {
+		return
+	}
+
+	status := statusOk
+	if span.failed {
+		status = statusError
+	}
+	encoded := map[string]interface{}{
+		"traceId":           span.traceID,
+		"spanId":            span.spanID,
+		"name":              span.name,
+		"kind":              span.kind,
+		"startTimeUnixNano": strconv.FormatInt(span.start.UnixNano(), 10),
+		"endTimeUnixNano":   strconv.FormatInt(time.Now().UnixNano(), 10),
+		"attributes":        attributeList(span.attributes),
+		"status":            map[string]interface{}{"code": status},
+	}
+	if span.parentID != "" {
+		encoded["parentSpanId"] = span.parentID
+	}
+
+	span.tracer.mutex.Lock()
+	span.tracer.spans = append(span.tracer.spans, encoded)
+	span.tracer.mutex.Unlock()
+}
+
+// Export sends the spans finished so far to endpoint, usually http://localhost:4318/v1/traces.
+func (tracer *Tracer) Export(endpoint string) error {
+	if tracer == nil {
+		return nil
+	}
+
+	tracer.mutex.Lock()
+	spans := tracer.spans
+	tracer.spans = nil
+	tracer.mutex.Unlock()
+
+	if len(spans) == 0 {
+		return nil
+	}
+
+	payload, err := json.Marshal(map[string]interface{}{
+		"resourceSpans": []interface{}{
+			map[string]interface{}{
+				"resource": map[string]interface{}{
+					"attributes": attributeList(map[string]interface{}{"service.name": tracer.Service}),
+				},
+				"scopeSpans": []interface{}{
+					map[string]interface{}{
+						"scope": map[string]interface{}{"name": tracer.Service},
+						"spans": spans,
+					},
+				},
+			},
+		},
+	})
+	if err != nil {
+		return err
+	}
+
+	r, err := http.Post(endpoint, "application/json", bytes.NewReader(payload))
+	if err != nil {
+		return err
+	}
+	defer r.Body.Close()
+
+	if r.StatusCode < 200 || r.StatusCode >= 300 {
+		return errors.Errorf("%s responded with %s", endpoint, r.Status)
+	}
+	return nil
+}
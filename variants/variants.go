@@ -0,0 +1,55 @@
+package variants
+
+import (
+	"sort"
+	"strings"
+)
+
+// Substitutions targets use when the handle they want is taken, leetspeak and look-alike letters.
+// Each one is tried in both directions.
+var substitutions = [][2]string{
+	{"o", "0"},
+	{"i", "1"},
+	{"l", "1"},
+	{"i", "l"},
+	{"e", "3"},
+	{"a", "4"},
+	{"s", "5"},
+	{"t", "7"},
+	{"b", "8"},
+	{"g", "9"},
+	{"w", "vv"},
+	{"m", "rn"},
+	{"d", "cl"},
+}
+
+// Generate returns the variants of username with one substitution, without the username itself.
+func Generate(username string) []string {
+	lower := strings.ToLower(username)
+	seen := map[string]bool{lower: true}
+	var variants []string
+
+	add := func(variant string) {
+		if !seen[variant] {
+			seen[variant] = true
+			variants = append(variants, variant)
+		}
+	}
+
+	for _, substitution := range substitutions {
+		for _, pair := range [][2]string{substitution, {substitution[1], substitution[0]}} {
+			from, to := pair[0], pair[1]
+			for i := strings.Index(lower, from); i >= 0; {
+				add(lower[:i] + to + lower[i+len(from):])
+				next := strings.Index(lower[i+1:], from)
+				if next < 0 {
+					break
+				}
+				i += next + 1
+			}
+		}
+	}
+
+	sort.Strings(variants)
+	return variants
+}